@@ -12,6 +12,31 @@ impl DiscriminatorConstants {
     pub const TWO_HOP_SWAP: [u8; 8] = [195, 96, 237, 108, 68, 162, 219, 230];
     pub const SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
 
+    pub const OPEN_POSITION: [u8; 8] = [135, 128, 47, 77, 15, 152, 240, 49];
+    pub const OPEN_POSITION_WITH_METADATA: [u8; 8] = [242, 29, 134, 48, 58, 110, 14, 60];
+    pub const OPEN_POSITION_WITH_TOKEN_EXTENSIONS: [u8; 8] = [212, 47, 95, 92, 114, 102, 131, 250];
+    pub const CLOSE_POSITION: [u8; 8] = [123, 134, 81, 0, 49, 68, 98, 98];
+    pub const CLOSE_POSITION_WITH_TOKEN_EXTENSIONS: [u8; 8] = [1, 182, 135, 59, 155, 25, 99, 223];
+
+    pub const INITIALIZE_POSITION_BUNDLE: [u8; 8] = [117, 45, 241, 149, 24, 18, 194, 65];
+    pub const INITIALIZE_POSITION_BUNDLE_WITH_METADATA: [u8; 8] =
+        [93, 124, 16, 179, 249, 131, 115, 245];
+    pub const OPEN_BUNDLED_POSITION: [u8; 8] = [169, 113, 126, 171, 213, 172, 212, 49];
+    pub const CLOSE_BUNDLED_POSITION: [u8; 8] = [41, 36, 216, 245, 27, 85, 103, 67];
+
+    pub const COLLECT_FEES: [u8; 8] = [164, 152, 207, 99, 30, 186, 19, 182];
+
+    pub const INITIALIZE_REWARD: [u8; 8] = [95, 135, 192, 196, 242, 129, 230, 68];
+    pub const SET_REWARD_EMISSIONS: [u8; 8] = [13, 197, 86, 168, 109, 176, 27, 244];
+    pub const COLLECT_REWARD: [u8; 8] = [70, 5, 132, 87, 86, 235, 177, 34];
+
+    pub const INITIALIZE_CONFIG: [u8; 8] = [208, 127, 21, 1, 194, 190, 196, 70];
+    pub const INITIALIZE_FEE_TIER: [u8; 8] = [183, 74, 156, 160, 112, 2, 42, 30];
+    pub const SET_DEFAULT_FEE_RATE: [u8; 8] = [118, 215, 214, 157, 182, 229, 208, 228];
+    pub const SET_DEFAULT_PROTOCOL_FEE_RATE: [u8; 8] = [107, 205, 249, 226, 151, 35, 86, 0];
+    pub const SET_FEE_RATE: [u8; 8] = [53, 243, 137, 65, 8, 140, 158, 6];
+    pub const SET_PROTOCOL_FEE_RATE: [u8; 8] = [95, 7, 4, 50, 154, 79, 156, 131];
+
     // V2
     pub const INITIALIZE_POOL_V2: [u8; 8] = [207, 45, 87, 242, 27, 63, 204, 67];
 
@@ -20,7 +45,62 @@ impl DiscriminatorConstants {
 
     pub const TWO_HOP_SWAP_V2: [u8; 8] = [186, 143, 209, 29, 254, 2, 194, 117];
     pub const SWAP_V2: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+
+    pub const COLLECT_FEES_V2: [u8; 8] = [207, 117, 95, 191, 229, 180, 226, 15];
+
+    pub const INITIALIZE_REWARD_V2: [u8; 8] = [91, 1, 77, 50, 235, 229, 133, 49];
+    pub const SET_REWARD_EMISSIONS_V2: [u8; 8] = [114, 228, 72, 32, 193, 48, 160, 102];
+    pub const COLLECT_REWARD_V2: [u8; 8] = [177, 107, 37, 180, 160, 19, 49, 209];
+}
+
+pub struct EventDiscriminatorConstants;
+
+impl EventDiscriminatorConstants {
+    // Prefix of the self-CPI instruction Anchor's `emit_cpi!` uses to log an event.
+    pub const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+    pub const POOL_INITIALIZED: [u8; 8] = [100, 118, 173, 87, 12, 198, 254, 229];
+    pub const LIQUIDITY_INCREASED: [u8; 8] = [30, 7, 144, 181, 102, 254, 155, 161];
+    pub const LIQUIDITY_DECREASED: [u8; 8] = [166, 1, 36, 71, 112, 202, 181, 171];
+    pub const TRADED: [u8; 8] = [225, 202, 73, 175, 147, 43, 160, 150];
 }
 
 pub const ZERO_STRING: &str = "0";
+
+// Fee rates are expressed in hundredths of a basis point of the swapped amount,
+// protocol fee rates in basis points of the fee.
+pub const FEE_RATE_DENOMINATOR: u64 = 1_000_000;
+pub const PROTOCOL_FEE_RATE_DENOMINATOR: u64 = 10_000;
+
+// Fallbacks for fee tiers and configs initialized before the start block.
+pub const DEFAULT_PROTOCOL_FEE_RATE: i64 = 300;
+pub const DEFAULT_FEE_RATES: [(u32, i64); 4] = [(1, 100), (8, 500), (64, 3000), (128, 10000)];
 pub const ORCA_WHIRLPOOL: [u8; 32] = b58!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+pub const TOKEN_PROGRAM: [u8; 32] = b58!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+pub const TOKEN_2022_PROGRAM: [u8; 32] = b58!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+// Tokens priced directly: stablecoins at one dollar and wrapped SOL from its stablecoin
+// pools. Every other token is priced from a pool pairing it with one of them.
+pub const WRAPPED_SOL: &str = "So11111111111111111111111111111111111111112";
+pub const STABLECOINS: [&str; 3] = [
+    "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", // USDC
+    "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", // USDT
+    "2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo", // PYUSD
+];
+
+// Minimum USD value of the reference token held by a pool for its price to be trusted,
+// so that thin pools can't be used to manipulate prices.
+pub const MINIMUM_SOL_PRICE_LIQUIDITY_USD: u64 = 100_000;
+pub const MINIMUM_PRICE_LIQUIDITY_USD: u64 = 10_000;
+
+// SPL Token instruction tags of `Transfer` and `TransferChecked`.
+pub const TRANSFER_INSTRUCTION: u8 = 3;
+pub const TRANSFER_CHECKED_INSTRUCTION: u8 = 12;
+
+// Token-2022 tag of the transfer fee extension instructions, and the tag of
+// `TransferCheckedWithFee` among them.
+pub const TRANSFER_FEE_EXTENSION_INSTRUCTION: u8 = 26;
+pub const TRANSFER_CHECKED_WITH_FEE_INSTRUCTION: u8 = 1;
+
+// Number of position slots of a position bundle.
+pub const POSITION_BUNDLE_SIZE: u32 = 256;
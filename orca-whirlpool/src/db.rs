@@ -1,23 +1,90 @@
 use crate::{
-    key_store::StoreKey,
-    pb::messari::orca_whirlpool::v1::{Deposits, Pool, Pools, Swaps, Withdraws},
+    constants,
+    key_store::{Granularity, StoreKey},
+    pb::messari::orca_whirlpool::v1::{
+        Configs, Deposits, FeeCollections, Pool, PoolPrice, Pools, Position, Positions,
+        RewardToken, Rewards, Swaps, Token, Withdraws,
+    },
+    utils,
 };
 
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
 use substreams::{
     key, log,
     pb::substreams::store_delta::Operation,
     scalar::{BigDecimal, BigInt},
     store::{
-        DeltaBigInt, DeltaExt, DeltaInt64, Deltas, StoreGet, StoreGetBigInt, StoreGetInt64,
-        StoreGetProto,
+        Delta, DeltaBigDecimal, DeltaBigInt, DeltaExt, DeltaInt64, DeltaProto, DeltaString, Deltas,
+        StoreGet, StoreGetBigDecimal, StoreGetBigInt, StoreGetInt64, StoreGetProto,
     },
 };
-use substreams_entity_change::tables::Tables;
+use substreams_entity_change::pb::entity::Value;
+use substreams_entity_change::tables::{Row, Tables};
+
+/// Stores read by the pool entity and its snapshots.
+pub struct PoolStores<'a> {
+    pub pools: &'a StoreGetProto<Pool>,
+    pub balances: &'a StoreGetBigInt,
+    pub liquidity: &'a StoreGetBigInt,
+    pub volume_by_token_amount: &'a StoreGetBigInt,
+    pub collected_fees: &'a StoreGetBigInt,
+    pub swap_fees: &'a StoreGetBigInt,
+    pub volume_usd: &'a StoreGetBigDecimal,
+    pub tvl: &'a StoreGetBigDecimal,
+    pub activity: &'a StoreGetBigInt,
+}
+
+/// Deltas of the pool stores in this block, which the pool entity and its snapshots are
+/// updated from.
+pub struct PoolDeltas<'a> {
+    pub balances: &'a Deltas<DeltaBigInt>,
+    pub liquidity: &'a Deltas<DeltaBigInt>,
+    pub volume_by_token_amount: &'a Deltas<DeltaBigInt>,
+    pub collected_fees: &'a Deltas<DeltaBigInt>,
+    pub fee_rates: &'a Deltas<DeltaInt64>,
+    pub swap_fees: &'a Deltas<DeltaBigInt>,
+    pub prices: &'a Deltas<DeltaProto<PoolPrice>>,
+    pub volume_usd: &'a Deltas<DeltaBigDecimal>,
+    pub tvl: &'a Deltas<DeltaBigDecimal>,
+    pub activity: &'a Deltas<DeltaBigInt>,
+    pub snapshot_days: &'a Deltas<DeltaInt64>,
+}
+
+/// Stores read by the financials daily snapshots.
+pub struct FinancialsStores<'a> {
+    pub financials: &'a StoreGetBigDecimal,
+    pub by_token: &'a StoreGetBigInt,
+    pub protocol_tvl: &'a StoreGetBigDecimal,
+}
+
+/// Deltas of the financials stores in this block, which the financials daily snapshots are
+/// updated from.
+pub struct FinancialsDeltas<'a> {
+    pub financials: &'a Deltas<DeltaBigDecimal>,
+    pub by_token: &'a Deltas<DeltaBigInt>,
+    pub protocol_tvl: &'a Deltas<DeltaBigDecimal>,
+}
+
+/// Stores read by the position entities and their snapshots.
+pub struct PositionStores<'a> {
+    pub pools: &'a StoreGetProto<Pool>,
+    pub positions: &'a StoreGetProto<Position>,
+    pub liquidity: &'a StoreGetBigInt,
+}
+
+/// Deltas of the position stores in this block, which the position entities are updated from.
+pub struct PositionDeltas<'a> {
+    pub liquidity: &'a Deltas<DeltaBigInt>,
+    pub owners: &'a Deltas<DeltaString>,
+}
 
 pub fn handle_protocol_entity(
     tables: &mut Tables,
     users_delta: Deltas<DeltaInt64>,
     pools_delta: Deltas<DeltaInt64>,
+    protocol_tvl_delta: &Deltas<DeltaBigDecimal>,
+    activity_counts_delta: &Deltas<DeltaBigInt>,
     protocol_id: &String,
     is_initialized: bool,
 ) {
@@ -26,16 +93,39 @@ pub fn handle_protocol_entity(
         tables
             .create_row("Protocol", protocol_id)
             .set("cumulativeUniqueUsers", BigInt::zero())
-            .set("totalPoolCount", BigInt::zero());
+            .set("cumulativeUniqueLPs", BigInt::zero())
+            .set("cumulativeUniqueDepositors", BigInt::zero())
+            .set("cumulativeUniqueWithdrawers", BigInt::zero())
+            .set("cumulativeUniqueTraders", BigInt::zero())
+            .set("cumulativeTransactionCount", BigInt::zero())
+            .set("cumulativeDepositCount", BigInt::zero())
+            .set("cumulativeWithdrawCount", BigInt::zero())
+            .set("cumulativeSwapCount", BigInt::zero())
+            .set("totalPoolCount", BigInt::zero())
+            .set("totalValueLockedUSD", BigDecimal::zero());
 
         return;
     }
 
-    // Update cumulative unique users
+    // Update cumulative unique users, liquidity providers, depositors, withdrawers and
+    // traders. The same store also holds the active users of each period, which are skipped.
     users_delta.iter().for_each(|delta| {
+        let field = match delta.key.as_str() {
+            x if x == StoreKey::CumulativeUsers.unique_id() => "cumulativeUniqueUsers",
+            x if x == StoreKey::CumulativeUniqueLPs.unique_id() => "cumulativeUniqueLPs",
+            x if x == StoreKey::CumulativeUniqueDepositors.unique_id() => {
+                "cumulativeUniqueDepositors"
+            }
+            x if x == StoreKey::CumulativeUniqueWithdrawers.unique_id() => {
+                "cumulativeUniqueWithdrawers"
+            }
+            x if x == StoreKey::CumulativeUniqueTraders.unique_id() => "cumulativeUniqueTraders",
+            _ => return,
+        };
+
         tables
             .update_row("Protocol", protocol_id)
-            .set("cumulativeUniqueUsers", delta.new_value);
+            .set(field, delta.new_value);
     });
 
     // Update total pool count
@@ -44,87 +134,304 @@ pub fn handle_protocol_entity(
             .update_row("Protocol", protocol_id)
             .set("totalPoolCount", delta.new_value);
     });
+
+    // Update total value locked
+    protocol_tvl_delta.iter().for_each(|delta| {
+        tables
+            .update_row("Protocol", protocol_id)
+            .set("totalValueLockedUSD", &delta.new_value);
+    });
+    // Update cumulative counts and volume. Snapshot keys of the same store are skipped.
+    activity_counts_delta
+        .iter()
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let field = match key::first_segment(&delta.key) {
+                x if x == StoreKey::TxnCount.unique_id() => "cumulativeTransactionCount",
+                x if x == StoreKey::DepositCount.unique_id() => "cumulativeDepositCount",
+                x if x == StoreKey::WithdrawCount.unique_id() => "cumulativeWithdrawCount",
+                x if x == StoreKey::SwapCount.unique_id() => "cumulativeSwapCount",
+                x if x == StoreKey::ProtocolVolumeByTokenAmount.unique_id() => {
+                    let token = key::segment_at(&delta.key, 1);
+
+                    let row = if delta.operation == Operation::Create {
+                        tables
+                            .create_row("ProtocolTokenVolume", token)
+                            .set("protocol", protocol_id)
+                            .set("token", token)
+                    } else {
+                        tables.update_row("ProtocolTokenVolume", token)
+                    };
+                    row.set("cumulativeVolumeTokenAmount", &delta.new_value);
+
+                    return;
+                }
+                _ => return,
+            };
+
+            tables
+                .update_row("Protocol", protocol_id)
+                .set(field, &delta.new_value);
+        });
 }
 
-pub fn handle_usage_metrics_daily_snapshot_entity(
+pub fn handle_financials_daily_snapshot_entity(
     tables: &mut Tables,
-    active_users_store: StoreGetInt64,
-    total_pool_count_store: StoreGetInt64,
-    user_activity_deltas: &Deltas<DeltaBigInt>,
+    stores: &FinancialsStores,
+    deltas: &FinancialsDeltas,
     protocol_id: &String,
+    day_id: i64,
     block_number: &BigInt,
     timestamp: &BigInt,
 ) {
-    let total_pool_count = total_pool_count_store
-        .get_last(StoreKey::TotalPoolCount.unique_id())
-        .unwrap_or_default();
-    let cumulative_users_count = active_users_store
-        .get_last(StoreKey::CumulativeUsers.unique_id())
-        .unwrap_or_default();
+    // Collect every snapshot touched in this block, so that each one is
+    // written once with all of its fields read from the stores.
+    let mut snapshots: BTreeSet<i64> = BTreeSet::new();
+    let mut token_snapshots: BTreeSet<(i64, String)> = BTreeSet::new();
 
-    user_activity_deltas
+    deltas
+        .financials
         .iter()
-        .key_first_segment_eq("UsageMetricsDailySnapshot")
+        .key_first_segment_eq("FinancialsDailySnapshot")
         .operation_not_eq(Operation::Delete)
         .for_each(|delta| {
             let day_id = key::segment_at(&delta.key, 1)
                 .parse::<i64>()
                 .unwrap_or_default();
-            let field_id = key::segment_at(&delta.key, 2);
 
-            let active_users_count = active_users_store
-                .get_last(
-                    StoreKey::UsageMetricsDailySnapshot(
-                        day_id,
-                        Some(Box::new(StoreKey::ActiveUsers)),
+            snapshots.insert(day_id);
+        });
+
+    deltas
+        .by_token
+        .iter()
+        .key_first_segment_eq("FinancialsDailySnapshot")
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let day_id = key::segment_at(&delta.key, 1)
+                .parse::<i64>()
+                .unwrap_or_default();
+            let token = key::segment_at(&delta.key, 3);
+
+            snapshots.insert(day_id);
+            token_snapshots.insert((day_id, token.to_string()));
+        });
+
+    if !deltas.protocol_tvl.deltas.is_empty() {
+        snapshots.insert(day_id);
+    }
+
+    let total_value_locked = stores
+        .protocol_tvl
+        .get_last(StoreKey::ProtocolTotalValueLocked.unique_id())
+        .unwrap_or_else(BigDecimal::zero);
+
+    snapshots.into_iter().for_each(|day_id| {
+        let row = tables
+            .update_row("FinancialsDailySnapshot", day_id.to_string())
+            .set("protocol", protocol_id)
+            .set("totalValueLockedUSD", &total_value_locked)
+            .set("blockNumber", block_number)
+            .set("timestamp", timestamp);
+
+        [
+            (StoreKey::DailyVolumeUSD, StoreKey::VolumeUSD, "VolumeUSD"),
+            (
+                StoreKey::DailyTotalRevenue,
+                StoreKey::TotalRevenue,
+                "TotalRevenueUSD",
+            ),
+            (
+                StoreKey::DailySupplySideRevenue,
+                StoreKey::SupplySideRevenue,
+                "SupplySideRevenueUSD",
+            ),
+            (
+                StoreKey::DailyProtocolSideRevenue,
+                StoreKey::ProtocolSideRevenue,
+                "ProtocolSideRevenueUSD",
+            ),
+        ]
+        .into_iter()
+        .for_each(|(daily_key, cumulative_key, field)| {
+            row.set(
+                &format!("daily{field}"),
+                stores
+                    .financials
+                    .get_last(
+                        StoreKey::FinancialsDailySnapshot(day_id, Some(Box::new(daily_key)))
+                            .unique_id(),
                     )
-                    .get_snapshot_key(None),
+                    .unwrap_or_else(BigDecimal::zero),
+            )
+            .set(
+                &format!("cumulative{field}"),
+                stores
+                    .financials
+                    .get_last(cumulative_key.unique_id())
+                    .unwrap_or_else(BigDecimal::zero),
+            );
+        });
+    });
+
+    token_snapshots.into_iter().for_each(|(day_id, token)| {
+        let daily_value = |field: StoreKey| {
+            stores
+                .by_token
+                .get_last(
+                    StoreKey::FinancialsDailySnapshot(day_id, Some(Box::new(field)))
+                        .get_unique_key(&token),
                 )
-                .unwrap_or_default();
+                .unwrap_or_default()
+        };
+
+        tables
+            .update_row("FinancialsDailyTokenSnapshot", format!("{day_id}-{token}"))
+            .set("snapshot", day_id.to_string())
+            .set("token", &token)
+            .set(
+                "dailyVolumeByTokenAmount",
+                daily_value(StoreKey::DailyVolumeByTokenAmount),
+            )
+            .set(
+                "dailyTotalRevenueByTokenAmount",
+                daily_value(StoreKey::DailyTotalRevenue),
+            )
+            .set(
+                "dailySupplySideRevenueByTokenAmount",
+                daily_value(StoreKey::DailySupplySideRevenue),
+            )
+            .set(
+                "dailyProtocolSideRevenueByTokenAmount",
+                daily_value(StoreKey::DailyProtocolSideRevenue),
+            )
+            .set("blockNumber", block_number)
+            .set("timestamp", timestamp);
+    });
+}
+
+pub fn handle_usage_metrics_snapshot_entity(
+    tables: &mut Tables,
+    active_users_store: &StoreGetInt64,
+    total_pool_count_store: &StoreGetInt64,
+    user_activity_deltas: &Deltas<DeltaBigInt>,
+    protocol_id: &String,
+    block_number: &BigInt,
+    timestamp: &BigInt,
+) {
+    let total_pool_count = total_pool_count_store
+        .get_last(StoreKey::TotalPoolCount.unique_id())
+        .unwrap_or_default();
+    let cumulative_users_count = active_users_store
+        .get_last(StoreKey::CumulativeUsers.unique_id())
+        .unwrap_or_default();
+    let cumulative_lps_count = active_users_store
+        .get_last(StoreKey::CumulativeUniqueLPs.unique_id())
+        .unwrap_or_default();
+    let cumulative_depositors_count = active_users_store
+        .get_last(StoreKey::CumulativeUniqueDepositors.unique_id())
+        .unwrap_or_default();
+    let cumulative_withdrawers_count = active_users_store
+        .get_last(StoreKey::CumulativeUniqueWithdrawers.unique_id())
+        .unwrap_or_default();
+    let cumulative_traders_count = active_users_store
+        .get_last(StoreKey::CumulativeUniqueTraders.unique_id())
+        .unwrap_or_default();
+
+    Granularity::ALL.into_iter().for_each(|granularity| {
+        // Snapshot keys are prefixed with the name of the entity they feed.
+        let entity = format!("UsageMetrics{}Snapshot", granularity.name());
+        let period = granularity.name().to_lowercase();
+
+        user_activity_deltas
+            .iter()
+            .key_first_segment_eq(&entity)
+            .operation_not_eq(Operation::Delete)
+            .for_each(|delta| {
+                let period_id = key::segment_at(&delta.key, 1)
+                    .parse::<i64>()
+                    .unwrap_or_default();
+                let field_id = key::segment_at(&delta.key, 2);
+
+                let active_count = |field: StoreKey| {
+                    active_users_store
+                        .get_last(
+                            granularity
+                                .usage_metrics_snapshot(period_id, Some(field))
+                                .get_snapshot_key(None),
+                        )
+                        .unwrap_or_default()
+                };
+
+                if field_id == StoreKey::TxnCount.unique_id() && delta.new_value == BigInt::one() {
+                    // Create a new snapshot
+                    let bigint0 = BigInt::zero();
+
+                    tables
+                        .update_row(&entity, period_id.to_string())
+                        .set("protocol", protocol_id)
+                        .set(&format!("{period}ActiveUsers"), &bigint0)
+                        .set(&format!("{period}ActiveDepositors"), &bigint0)
+                        .set(&format!("{period}ActiveWithdrawers"), &bigint0)
+                        .set(&format!("{period}ActiveTraders"), &bigint0)
+                        .set("cumulativeUniqueUsers", &bigint0)
+                        .set("cumulativeUniqueLPs", &bigint0)
+                        .set("cumulativeUniqueDepositors", &bigint0)
+                        .set("cumulativeUniqueWithdrawers", &bigint0)
+                        .set("cumulativeUniqueTraders", &bigint0)
+                        .set(&format!("{period}TransactionCount"), &bigint0)
+                        .set(&format!("{period}DepositCount"), &bigint0)
+                        .set(&format!("{period}WithdrawCount"), &bigint0)
+                        .set(&format!("{period}SwapCount"), &bigint0)
+                        .set("totalPoolCount", total_pool_count)
+                        .set("blockNumber", block_number)
+                        .set("timestamp", timestamp);
+                }
 
-            if field_id == StoreKey::TxnCount.unique_id() && delta.new_value == BigInt::one() {
-                // Create a new daily snapshot
-                let bigint0 = BigInt::zero();
+                let field = match field_id {
+                    x if x == StoreKey::SwapCount.unique_id() => "SwapCount",
+                    x if x == StoreKey::DepositCount.unique_id() => "DepositCount",
+                    x if x == StoreKey::WithdrawCount.unique_id() => "WithdrawCount",
+                    x if x == StoreKey::TxnCount.unique_id() => "TransactionCount",
+                    _ => return,
+                };
 
                 tables
-                    .update_row("UsageMetricsDailySnapshot", day_id.to_string())
-                    .set("protocol", protocol_id)
-                    .set("dailyActiveUsers", &bigint0)
-                    .set("cumulativeUniqueUsers", &bigint0)
-                    .set("dailyTransactionCount", &bigint0)
-                    .set("dailyDepositCount", &bigint0)
-                    .set("dailyWithdrawCount", &bigint0)
-                    .set("dailySwapCount", &bigint0)
+                    .update_row(&entity, period_id.to_string())
+                    .set(
+                        &format!("{period}ActiveUsers"),
+                        active_count(StoreKey::ActiveUsers),
+                    )
+                    .set(
+                        &format!("{period}ActiveDepositors"),
+                        active_count(StoreKey::ActiveDepositors),
+                    )
+                    .set(
+                        &format!("{period}ActiveWithdrawers"),
+                        active_count(StoreKey::ActiveWithdrawers),
+                    )
+                    .set(
+                        &format!("{period}ActiveTraders"),
+                        active_count(StoreKey::ActiveTraders),
+                    )
+                    .set("cumulativeUniqueUsers", cumulative_users_count)
+                    .set("cumulativeUniqueLPs", cumulative_lps_count)
+                    .set("cumulativeUniqueDepositors", cumulative_depositors_count)
+                    .set("cumulativeUniqueWithdrawers", cumulative_withdrawers_count)
+                    .set("cumulativeUniqueTraders", cumulative_traders_count)
+                    .set(&format!("{period}{field}"), &delta.new_value)
                     .set("totalPoolCount", total_pool_count)
                     .set("blockNumber", block_number)
                     .set("timestamp", timestamp);
-            }
-
-            let field = match field_id {
-                x if x == StoreKey::SwapCount.unique_id() => "dailySwapCount",
-                x if x == StoreKey::DepositCount.unique_id() => "dailyDepositCount",
-                x if x == StoreKey::WithdrawCount.unique_id() => "dailyWithdrawCount",
-                x if x == StoreKey::TxnCount.unique_id() => "dailyTransactionCount",
-                _ => "",
-            };
-
-            tables
-                .update_row("UsageMetricsDailySnapshot", day_id.to_string())
-                .set("dailyActiveUsers", active_users_count)
-                .set("cumulativeUniqueUsers", cumulative_users_count)
-                .set(field, &delta.new_value)
-                .set("totalPoolCount", total_pool_count)
-                .set("blockNumber", block_number)
-                .set("timestamp", timestamp);
-        });
+            });
+    });
 }
 
 pub fn handle_pool_entity(
     tables: &mut Tables,
     initialized_pools: Pools,
-    pool_store: &StoreGetProto<Pool>,
-    pool_balances_delta: &Deltas<DeltaBigInt>,
-    pool_liquidity_delta: &Deltas<DeltaBigInt>,
+    stores: &PoolStores,
+    deltas: &PoolDeltas,
     protocol_id: &String,
 ) {
     initialized_pools.data.iter().for_each(|pool| {
@@ -136,25 +443,75 @@ pub fn handle_pool_entity(
                 vec![pool.token_mint_a.clone(), pool.token_mint_b.clone()],
             )
             .set("outputToken", &pool.address)
-            .set("token0Balance", &BigInt::zero())
-            .set("token1Balance", &BigInt::zero())
+            .set("whirlpoolsConfig", &pool.whirlpools_config)
+            .set("feeTier", &pool.fee_tier)
+            .set("tickSpacing", pool.tick_spacing as i32)
+            .set(
+                "feeRate",
+                initial_fee_rate(deltas.fee_rates, StoreKey::PoolFeeRate, &pool.address)
+                    .unwrap_or_else(|| utils::default_fee_rate(pool.tick_spacing))
+                    as i32,
+            )
+            .set(
+                "protocolFeeRate",
+                initial_fee_rate(
+                    deltas.fee_rates,
+                    StoreKey::PoolProtocolFeeRate,
+                    &pool.address,
+                )
+                .unwrap_or(constants::DEFAULT_PROTOCOL_FEE_RATE) as i32,
+            )
+            .set("tokenProgramA", &pool.token_program_a)
+            .set("tokenProgramB", &pool.token_program_b)
+            .set_bigint("initialSqrtPrice", &pool.initial_sqrt_price)
+            .set(
+                "initialPrice",
+                utils::sqrt_price_x64_to_price(&pool.initial_sqrt_price),
+            )
+            // Replaced by the pool price, which is left unset when the decimals of a mint
+            // are unknown.
+            .set_bigint("sqrtPriceX64", &pool.initial_sqrt_price)
+            .set("token0Price", BigDecimal::zero())
+            .set("token1Price", BigDecimal::zero())
+            .set("isPriceApproximated", false)
+            .set("token0Balance", BigInt::zero())
+            .set("token1Balance", BigInt::zero())
             .set(
                 "cumulativeVolumeByTokenAmount",
                 vec![BigInt::zero(), BigInt::zero()],
             )
-            .set("outputTokenSupply", &BigInt::zero())
+            .set("cumulativeVolumeUSD", BigDecimal::zero())
+            .set("totalValueLockedUSD", BigDecimal::zero())
+            .set("outputTokenSupply", BigInt::zero())
+            .set("cumulativeCollectedFeesTokenA", BigInt::zero())
+            .set("cumulativeCollectedFeesTokenB", BigInt::zero())
+            .set("cumulativeTotalRevenueTokenA", BigInt::zero())
+            .set("cumulativeTotalRevenueTokenB", BigInt::zero())
+            .set("cumulativeSupplySideRevenueTokenA", BigInt::zero())
+            .set("cumulativeSupplySideRevenueTokenB", BigInt::zero())
+            .set("cumulativeProtocolSideRevenueTokenA", BigInt::zero())
+            .set("cumulativeProtocolSideRevenueTokenB", BigInt::zero())
+            .set("cumulativeTransactionCount", BigInt::zero())
+            .set("cumulativeDepositCount", BigInt::zero())
+            .set("cumulativeWithdrawCount", BigInt::zero())
+            .set("cumulativeSwapCount", BigInt::zero())
+            .set("cumulativeUniqueUsers", BigInt::zero())
             .set("createdTimestamp", pool.created_timestamp)
             .set("createdBlockNumber", pool.created_block_number);
     });
 
-    pool_balances_delta
+    deltas
+        .balances
         .iter()
         .key_first_segment_eq(StoreKey::PoolBalance.unique_id())
         .for_each(|delta| {
             let pool_address = key::segment_at(&delta.key, 1);
             let input_token = key::segment_at(&delta.key, 2);
 
-            let pool = match pool_store.get_last(StoreKey::Pool.get_unique_key(pool_address)) {
+            let pool = match stores
+                .pools
+                .get_last(StoreKey::Pool.get_unique_key(pool_address))
+            {
                 Some(pool) => pool,
                 None => {
                     log::info!("Pool not found: {:?}", pool_address);
@@ -175,7 +532,8 @@ pub fn handle_pool_entity(
                 .set(balance_field, &delta.new_value);
         });
 
-    pool_liquidity_delta
+    deltas
+        .liquidity
         .iter()
         .key_first_segment_eq(StoreKey::PoolLiquidity.unique_id())
         .for_each(|delta| {
@@ -185,30 +543,19 @@ pub fn handle_pool_entity(
                 .update_row("LiquidityPool", pool)
                 .set("outputTokenSupply", &delta.new_value);
         });
-}
-
-pub fn handle_liquidity_pool_daily_snapshot_entity(
-    tables: &mut Tables,
-    pool_store: &StoreGetProto<Pool>,
-    pool_balances_store: &StoreGetBigInt,
-    pool_liquidity_store: &StoreGetBigInt,
-    volume_by_token_amount_deltas: &Deltas<DeltaBigInt>,
-    protocol_id: &String,
-    block_number: &BigInt,
-    timestamp: &BigInt,
-) {
-    let bigint0 = BigInt::zero();
 
-    volume_by_token_amount_deltas
+    deltas
+        .collected_fees
         .iter()
-        .key_first_segment_eq("PoolDailySnapshot")
-        .operation_not_eq(Operation::Delete)
+        .key_first_segment_eq(StoreKey::CollectedFees.unique_id())
         .for_each(|delta| {
-            let day_id = key::segment_at(&delta.key, 1);
-            let pool_address = key::segment_at(&delta.key, 3);
-            let token_address = key::segment_at(&delta.key, 4);
+            let pool_address = key::segment_at(&delta.key, 1);
+            let token = key::segment_at(&delta.key, 2);
 
-            let pool = match pool_store.get_last(StoreKey::Pool.get_unique_key(pool_address)) {
+            let pool = match stores
+                .pools
+                .get_last(StoreKey::Pool.get_unique_key(pool_address))
+            {
                 Some(pool) => pool,
                 None => {
                     log::info!("Pool not found: {:?}", pool_address);
@@ -216,111 +563,957 @@ pub fn handle_liquidity_pool_daily_snapshot_entity(
                 }
             };
 
-            if delta.old_value == BigInt::zero() {
-                tables
-                    .update_row(
-                        "LiquidityPoolDailySnapshot",
-                        format!("{pool_address}-{day_id}"),
-                    )
-                    .set("protocol", protocol_id)
-                    .set("pool", pool_address)
-                    .set("blockNumber", block_number)
-                    .set("timestamp", timestamp)
-                    .set("dailyVolumeByToken0Amount", &bigint0)
-                    .set("dailyVolumeByToken1Amount", &bigint0)
-                    .set("token0Balances", &bigint0)
-                    .set("token1Balances", &bigint0)
-                    .set("outputTokenSupply", &bigint0);
-            }
-
-            let (volume_field, balance_field, token_balance) = if token_address == pool.token_mint_a
-            {
-                (
-                    "dailyVolumeByToken0Amount",
-                    "token0Balances",
-                    pool_balances_store
-                        .get_last(
-                            StoreKey::PoolBalance.get_unique_keys(pool_address, token_address),
-                        )
-                        .unwrap_or_default(),
-                )
-            } else if token_address == pool.token_mint_b {
-                (
-                    "dailyVolumeByToken1Amount",
-                    "token1Balances",
-                    pool_balances_store
-                        .get_last(
-                            StoreKey::PoolBalance.get_unique_keys(pool_address, token_address),
-                        )
-                        .unwrap_or_default(),
-                )
+            let fees_field = if token == pool.token_mint_a {
+                "cumulativeCollectedFeesTokenA"
+            } else if token == pool.token_mint_b {
+                "cumulativeCollectedFeesTokenB"
             } else {
                 return;
             };
 
-            let output_token_supply = pool_liquidity_store
-                .get_last(StoreKey::PoolLiquidity.get_unique_key(pool_address))
-                .unwrap_or_default();
+            tables
+                .update_row("LiquidityPool", pool_address)
+                .set(fees_field, &delta.new_value);
+        });
+
+    deltas
+        .fee_rates
+        .iter()
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let field = match key::first_segment(&delta.key) {
+                x if x == StoreKey::PoolFeeRate.unique_id() => "feeRate",
+                x if x == StoreKey::PoolProtocolFeeRate.unique_id() => "protocolFeeRate",
+                _ => return,
+            };
+            let pool = key::segment_at(&delta.key, 1);
 
             tables
-                .update_row(
-                    "LiquidityPoolDailySnapshot",
-                    format!("{pool_address}-{day_id}"),
-                )
-                .set(volume_field, &delta.new_value)
-                .set(balance_field, &token_balance)
-                .set("outputTokenSupply", &output_token_supply)
-                .set("blockNumber", block_number)
-                .set("timestamp", timestamp);
+                .update_row("LiquidityPool", pool)
+                .set(field, delta.new_value as i32);
         });
-}
 
-pub fn handle_deposit_entity(tables: &mut Tables, map_deposits: Deposits, protocol_id: &String) {
-    map_deposits.data.iter().for_each(|deposit| {
-        tables
-            .create_row("Deposit", &deposit.id)
-            .set("blockHash", &deposit.block_hash)
-            .set("protocol", protocol_id)
-            .set("to", &deposit.to)
-            .set("from", &deposit.from)
-            .set("slot", BigInt::from(deposit.slot))
-            .set("blockNumber", BigInt::from(deposit.block_height))
-            .set("timestamp", BigInt::from(deposit.block_timestamp))
-            .set(
-                "inputTokens",
-                vec![deposit.token_a.clone(), deposit.token_b.clone()],
-            )
-            .set("outputToken", &deposit.to)
-            .set(
-                "inputTokenAmounts",
-                vec![
-                    BigInt::try_from(deposit.amount_a.clone()).unwrap(),
-                    BigInt::try_from(deposit.amount_b.clone()).unwrap(),
-                ],
-            )
-            .set(
-                "outputTokenAmount",
-                BigInt::try_from(deposit.output_amount.clone()).unwrap(),
-            )
-            .set("amountUSD", &BigDecimal::zero())
-            .set("pool", &deposit.to);
+    [
+        (StoreKey::TotalRevenue, "cumulativeTotalRevenue"),
+        (StoreKey::SupplySideRevenue, "cumulativeSupplySideRevenue"),
+        (
+            StoreKey::ProtocolSideRevenue,
+            "cumulativeProtocolSideRevenue",
+        ),
+    ]
+    .into_iter()
+    .for_each(|(store_key, field_prefix)| {
+        deltas
+            .swap_fees
+            .iter()
+            .key_first_segment_eq(store_key.unique_id())
+            .for_each(|delta| {
+                let pool_address = key::segment_at(&delta.key, 1);
+                let token = key::segment_at(&delta.key, 2);
+
+                let pool = match stores
+                    .pools
+                    .get_last(StoreKey::Pool.get_unique_key(pool_address))
+                {
+                    Some(pool) => pool,
+                    None => {
+                        log::info!("Pool not found: {:?}", pool_address);
+                        return;
+                    }
+                };
+
+                let revenue_field = if token == pool.token_mint_a {
+                    format!("{field_prefix}TokenA")
+                } else if token == pool.token_mint_b {
+                    format!("{field_prefix}TokenB")
+                } else {
+                    return;
+                };
+
+                tables
+                    .update_row("LiquidityPool", pool_address)
+                    .set(&revenue_field, &delta.new_value);
+            });
+    });
+
+    deltas
+        .prices
+        .iter()
+        .key_first_segment_eq(StoreKey::PoolPrice.unique_id())
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let pool = key::segment_at(&delta.key, 1);
+
+            set_pool_price(tables.update_row("LiquidityPool", pool), &delta.new_value);
+        });
+
+    // The volume of both tokens is set together, so only one update is needed per pool.
+    let swapped_pools: BTreeSet<&str> = deltas
+        .volume_by_token_amount
+        .iter()
+        .key_first_segment_eq(StoreKey::VolumeByTokenAmount.unique_id())
+        .map(|delta| key::segment_at(&delta.key, 1))
+        .collect();
+
+    swapped_pools.into_iter().for_each(|pool_address| {
+        let pool = match stores
+            .pools
+            .get_last(StoreKey::Pool.get_unique_key(pool_address))
+        {
+            Some(pool) => pool,
+            None => {
+                log::info!("Pool not found: {:?}", pool_address);
+                return;
+            }
+        };
+
+        let volume = |token: &str| {
+            stores
+                .volume_by_token_amount
+                .get_last(StoreKey::VolumeByTokenAmount.get_unique_keys(pool_address, token))
+                .unwrap_or_default()
+        };
+
+        tables.update_row("LiquidityPool", pool_address).set(
+            "cumulativeVolumeByTokenAmount",
+            vec![volume(&pool.token_mint_a), volume(&pool.token_mint_b)],
+        );
     });
+
+    deltas
+        .volume_usd
+        .iter()
+        .key_first_segment_eq(StoreKey::VolumeUSD.unique_id())
+        .for_each(|delta| {
+            let pool = key::segment_at(&delta.key, 1);
+
+            tables
+                .update_row("LiquidityPool", pool)
+                .set("cumulativeVolumeUSD", &delta.new_value);
+        });
+
+    deltas
+        .tvl
+        .iter()
+        .key_first_segment_eq(StoreKey::PoolTotalValueLocked.unique_id())
+        .for_each(|delta| {
+            let pool = key::segment_at(&delta.key, 1);
+
+            tables
+                .update_row("LiquidityPool", pool)
+                .set("totalValueLockedUSD", &delta.new_value);
+        });
+
+    // Daily snapshot keys of the same store are skipped.
+    deltas
+        .activity
+        .iter()
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let field = match key::first_segment(&delta.key) {
+                x if x == StoreKey::TxnCount.unique_id() => "cumulativeTransactionCount",
+                x if x == StoreKey::DepositCount.unique_id() => "cumulativeDepositCount",
+                x if x == StoreKey::WithdrawCount.unique_id() => "cumulativeWithdrawCount",
+                x if x == StoreKey::SwapCount.unique_id() => "cumulativeSwapCount",
+                x if x == StoreKey::CumulativeUsers.unique_id() => "cumulativeUniqueUsers",
+                _ => return,
+            };
+            let pool = key::segment_at(&delta.key, 1);
+
+            tables
+                .update_row("LiquidityPool", pool)
+                .set(field, &delta.new_value);
+        });
 }
 
-pub fn handle_withdraw_entity(
+pub fn handle_token_entity(
     tables: &mut Tables,
-    map_withdraws: Withdraws,
-    protocol_id: &String,
+    tokens_delta: &Deltas<DeltaProto<Token>>,
+    token_prices_delta: &Deltas<DeltaBigDecimal>,
+    deposits: &Deposits,
+    withdraws: &Withdraws,
+    swaps: &Swaps,
+    block_number: &BigInt,
 ) {
-    map_withdraws.data.iter().for_each(|withdraw| {
-        tables
-            .create_row("Withdraw", &withdraw.id)
-            .set("blockHash", &withdraw.block_hash)
-            .set("protocol", protocol_id)
-            .set("to", &withdraw.to)
-            .set("from", &withdraw.from)
-            .set("slot", BigInt::from(withdraw.slot))
-            .set("blockNumber", BigInt::from(withdraw.block_height))
+    let token_2022_program = bs58::encode(constants::TOKEN_2022_PROGRAM).into_string();
+
+    tokens_delta
+        .iter()
+        .key_first_segment_eq(StoreKey::Token.unique_id())
+        .operation_eq(Operation::Create)
+        .for_each(|delta| {
+            let token = &delta.new_value;
+
+            let row = tables
+                .create_row("Token", &token.address)
+                .set("tokenProgram", &token.token_program)
+                .set("isToken2022", token.token_program == token_2022_program)
+                .set("hasTransferFee", false);
+
+            if let Some(decimals) = token.decimals {
+                row.set("decimals", decimals as i32);
+            }
+        });
+
+    // Flag the tokens a transfer fee was withheld on in this block
+    deposits
+        .data
+        .iter()
+        .flat_map(|deposit| {
+            [
+                (&deposit.token_a, &deposit.transfer_fee_a),
+                (&deposit.token_b, &deposit.transfer_fee_b),
+            ]
+        })
+        .chain(withdraws.data.iter().flat_map(|withdraw| {
+            [
+                (&withdraw.token_a, &withdraw.transfer_fee_a),
+                (&withdraw.token_b, &withdraw.transfer_fee_b),
+            ]
+        }))
+        .chain(swaps.data.iter().flat_map(|swap| {
+            [
+                (&swap.token_in, &swap.transfer_fee_in),
+                (&swap.token_out, &swap.transfer_fee_out),
+            ]
+        }))
+        .filter(|(_, transfer_fee)| {
+            BigInt::try_from(*transfer_fee).unwrap_or_default() > BigInt::zero()
+        })
+        .map(|(token, _)| token)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .for_each(|token| {
+            tables
+                .update_row("Token", token)
+                .set("hasTransferFee", true);
+        });
+
+    token_prices_delta
+        .iter()
+        .key_first_segment_eq(StoreKey::TokenPrice.unique_id())
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let token = key::segment_at(&delta.key, 1);
+
+            tables
+                .update_row("Token", token)
+                .set("lastPriceUSD", &delta.new_value)
+                .set("lastPriceBlockNumber", block_number);
+        });
+}
+
+/// Rate a pool was initialized with in this block, which `store_pool_fee_rates` takes
+/// from the pool's fee tier and config.
+fn initial_fee_rate(
+    fee_rates_deltas: &Deltas<DeltaInt64>,
+    store_key: StoreKey,
+    pool_address: &str,
+) -> Option<i64> {
+    let key = store_key.get_unique_key(pool_address);
+
+    fee_rates_deltas
+        .deltas
+        .iter()
+        .find(|delta| delta.key == key)
+        .map(|delta| delta.new_value)
+}
+
+fn set_pool_price(row: &mut Row, price: &PoolPrice) {
+    row.set_bigint("sqrtPriceX64", &price.sqrt_price_x64)
+        .set_bigdecimal("token0Price", &price.token_a_price)
+        .set_bigdecimal("token1Price", &price.token_b_price)
+        .set("isPriceApproximated", price.approximated);
+
+    if let Some(tick) = price.tick {
+        row.set("currentTick", tick);
+    }
+}
+
+pub fn handle_liquidity_pool_daily_snapshot_entity(
+    tables: &mut Tables,
+    stores: &PoolStores,
+    deltas: &PoolDeltas,
+    protocol_id: &String,
+    day_id: i64,
+    block_number: &BigInt,
+    timestamp: &BigInt,
+) {
+    // Collect every pool snapshot touched in this block, so that each one is
+    // written once with all of its fields read from the stores. Snapshots of
+    // the days a pool was idle are flagged to be carried forward.
+    let mut snapshots: BTreeMap<(String, i64), bool> = BTreeMap::new();
+
+    [
+        deltas.volume_by_token_amount,
+        deltas.collected_fees,
+        deltas.swap_fees,
+        deltas.activity,
+    ]
+    .into_iter()
+    .for_each(|deltas| {
+        deltas
+            .iter()
+            .key_first_segment_eq("PoolDailySnapshot")
+            .operation_not_eq(Operation::Delete)
+            .for_each(|delta| {
+                let day_id = key::segment_at(&delta.key, 1)
+                    .parse::<i64>()
+                    .unwrap_or_default();
+                let pool_address = key::segment_at(&delta.key, 3);
+
+                snapshots.insert((pool_address.to_string(), day_id), false);
+            });
+    });
+
+    deltas
+        .volume_usd
+        .iter()
+        .key_first_segment_eq("PoolDailySnapshot")
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let day_id = key::segment_at(&delta.key, 1)
+                .parse::<i64>()
+                .unwrap_or_default();
+            let pool_address = key::segment_at(&delta.key, 3);
+
+            snapshots.insert((pool_address.to_string(), day_id), false);
+        });
+
+    // Deposits, withdrawals and fee collections change the balances or the
+    // liquidity of a pool without any volume.
+    [deltas.balances, deltas.liquidity]
+        .into_iter()
+        .for_each(|deltas| {
+            deltas
+                .iter()
+                .operation_not_eq(Operation::Delete)
+                .for_each(|delta| {
+                    let pool_address = key::segment_at(&delta.key, 1);
+
+                    snapshots.insert((pool_address.to_string(), day_id), false);
+                });
+        });
+
+    // Days between the last active day of a pool and today had no changes, so
+    // their snapshots repeat the pool's state from before this block.
+    deltas
+        .snapshot_days
+        .iter()
+        .key_first_segment_eq(StoreKey::PoolSnapshotDay.unique_id())
+        .operation_eq(Operation::Update)
+        .for_each(|delta| {
+            let pool_address = key::segment_at(&delta.key, 1);
+
+            (delta.old_value + 1..delta.new_value).for_each(|idle_day_id| {
+                snapshots.insert((pool_address.to_string(), idle_day_id), true);
+            });
+        });
+
+    // Block in which each pool was last active before this block, which is the last
+    // block of the state its carried forward snapshots repeat.
+    let last_active_blocks: HashMap<String, BigInt> = deltas
+        .snapshot_days
+        .iter()
+        .key_first_segment_eq(StoreKey::PoolSnapshotBlock.unique_id())
+        .operation_eq(Operation::Update)
+        .map(|delta| {
+            (
+                key::segment_at(&delta.key, 1).to_string(),
+                BigInt::from(delta.old_value),
+            )
+        })
+        .collect();
+
+    let balances_before = values_before(deltas.balances, |d| &d.old_value);
+    let liquidity_before = values_before(deltas.liquidity, |d| &d.old_value);
+    let collected_fees_before = values_before(deltas.collected_fees, |d| &d.old_value);
+    let swap_fees_before = values_before(deltas.swap_fees, |d| &d.old_value);
+    let volume_usd_before = values_before(deltas.volume_usd, |d| &d.old_value);
+    let pool_tvl_before = values_before(deltas.tvl, |d| &d.old_value);
+    let pool_activity_before = values_before(deltas.activity, |d| &d.old_value);
+
+    snapshots
+        .into_iter()
+        .for_each(|((pool_address, day_id), carried_forward)| {
+            let pool = match stores
+                .pools
+                .get_last(StoreKey::Pool.get_unique_key(&pool_address))
+            {
+                Some(pool) => pool,
+                None => {
+                    log::info!("Pool not found: {:?}", pool_address);
+                    return;
+                }
+            };
+
+            let daily_value = |store: &StoreGetBigInt, field: StoreKey, token: &str| {
+                if carried_forward {
+                    return BigInt::zero();
+                }
+
+                store
+                    .get_last(
+                        StoreKey::PoolDailySnapshot(day_id, Some(Box::new(field)))
+                            .get_unique_keys(&pool_address, token),
+                    )
+                    .unwrap_or_default()
+            };
+            let daily_count = |field: StoreKey| {
+                if carried_forward {
+                    return BigInt::zero();
+                }
+
+                stores
+                    .activity
+                    .get_last(
+                        StoreKey::PoolDailySnapshot(day_id, Some(Box::new(field)))
+                            .get_unique_key(&pool_address),
+                    )
+                    .unwrap_or_default()
+            };
+            let state = |store: &StoreGetBigInt, before: &HashMap<String, BigInt>, key: String| {
+                match carried_forward {
+                    true => value_before(store, before, &key),
+                    false => store.get_last(&key),
+                }
+                .unwrap_or_default()
+            };
+            let state_usd =
+                |store: &StoreGetBigDecimal, before: &HashMap<String, BigDecimal>, key: String| {
+                    match carried_forward {
+                        true => value_before(store, before, &key),
+                        false => store.get_last(&key),
+                    }
+                    .unwrap_or_else(BigDecimal::zero)
+                };
+
+            let pool_value = |store: &StoreGetBigInt,
+                              before: &HashMap<String, BigInt>,
+                              field: StoreKey,
+                              token: &str| {
+                state(store, before, field.get_unique_keys(&pool_address, token))
+            };
+
+            // Carried forward snapshots are stamped with the start of their day, and with
+            // the block the pool was last active in.
+            let timestamp = match carried_forward {
+                true => BigInt::from(day_id * 86400),
+                false => timestamp.clone(),
+            };
+            let block_number = match carried_forward {
+                true => last_active_blocks
+                    .get(&pool_address)
+                    .cloned()
+                    .unwrap_or_else(|| block_number.clone()),
+                false => block_number.clone(),
+            };
+
+            let row = tables
+                .update_row(
+                    "LiquidityPoolDailySnapshot",
+                    format!("{pool_address}-{day_id}"),
+                )
+                .set("protocol", protocol_id)
+                .set("pool", &pool_address)
+                .set("blockNumber", &block_number)
+                .set("timestamp", &timestamp)
+                .set(
+                    "dailyVolumeByToken0Amount",
+                    daily_value(
+                        stores.volume_by_token_amount,
+                        StoreKey::DailyVolumeByTokenAmount,
+                        &pool.token_mint_a,
+                    ),
+                )
+                .set(
+                    "dailyVolumeByToken1Amount",
+                    daily_value(
+                        stores.volume_by_token_amount,
+                        StoreKey::DailyVolumeByTokenAmount,
+                        &pool.token_mint_b,
+                    ),
+                )
+                .set(
+                    "token0Balances",
+                    pool_value(
+                        stores.balances,
+                        &balances_before,
+                        StoreKey::PoolBalance,
+                        &pool.token_mint_a,
+                    ),
+                )
+                .set(
+                    "token1Balances",
+                    pool_value(
+                        stores.balances,
+                        &balances_before,
+                        StoreKey::PoolBalance,
+                        &pool.token_mint_b,
+                    ),
+                )
+                .set(
+                    "outputTokenSupply",
+                    state(
+                        stores.liquidity,
+                        &liquidity_before,
+                        StoreKey::PoolLiquidity.get_unique_key(&pool_address),
+                    ),
+                )
+                .set(
+                    "dailyCollectedFeesTokenA",
+                    daily_value(
+                        stores.collected_fees,
+                        StoreKey::DailyCollectedFees,
+                        &pool.token_mint_a,
+                    ),
+                )
+                .set(
+                    "dailyCollectedFeesTokenB",
+                    daily_value(
+                        stores.collected_fees,
+                        StoreKey::DailyCollectedFees,
+                        &pool.token_mint_b,
+                    ),
+                )
+                .set(
+                    "cumulativeCollectedFeesTokenA",
+                    pool_value(
+                        stores.collected_fees,
+                        &collected_fees_before,
+                        StoreKey::CollectedFees,
+                        &pool.token_mint_a,
+                    ),
+                )
+                .set(
+                    "cumulativeCollectedFeesTokenB",
+                    pool_value(
+                        stores.collected_fees,
+                        &collected_fees_before,
+                        StoreKey::CollectedFees,
+                        &pool.token_mint_b,
+                    ),
+                )
+                .set(
+                    "dailyVolumeUSD",
+                    match carried_forward {
+                        true => None,
+                        false => stores.volume_usd.get_last(
+                            StoreKey::PoolDailySnapshot(
+                                day_id,
+                                Some(Box::new(StoreKey::DailyVolumeUSD)),
+                            )
+                            .get_unique_key(&pool_address),
+                        ),
+                    }
+                    .unwrap_or_else(BigDecimal::zero),
+                )
+                .set(
+                    "cumulativeVolumeUSD",
+                    state_usd(
+                        stores.volume_usd,
+                        &volume_usd_before,
+                        StoreKey::VolumeUSD.get_unique_key(&pool_address),
+                    ),
+                )
+                .set(
+                    "totalValueLockedUSD",
+                    state_usd(
+                        stores.tvl,
+                        &pool_tvl_before,
+                        StoreKey::PoolTotalValueLocked.get_unique_key(&pool_address),
+                    ),
+                );
+
+            [
+                (StoreKey::TxnCount, "TransactionCount"),
+                (StoreKey::DepositCount, "DepositCount"),
+                (StoreKey::WithdrawCount, "WithdrawCount"),
+                (StoreKey::SwapCount, "SwapCount"),
+            ]
+            .into_iter()
+            .for_each(|(count_type, field)| {
+                row.set(&format!("daily{field}"), daily_count(count_type.clone()))
+                    .set(
+                        &format!("cumulative{field}"),
+                        state(
+                            stores.activity,
+                            &pool_activity_before,
+                            count_type.get_unique_key(&pool_address),
+                        ),
+                    );
+            });
+
+            row.set("dailyActiveUsers", daily_count(StoreKey::ActiveUsers))
+                .set(
+                    "cumulativeUniqueUsers",
+                    state(
+                        stores.activity,
+                        &pool_activity_before,
+                        StoreKey::CumulativeUsers.get_unique_key(&pool_address),
+                    ),
+                );
+
+            [
+                (
+                    StoreKey::DailyTotalRevenue,
+                    StoreKey::TotalRevenue,
+                    "TotalRevenue",
+                ),
+                (
+                    StoreKey::DailySupplySideRevenue,
+                    StoreKey::SupplySideRevenue,
+                    "SupplySideRevenue",
+                ),
+                (
+                    StoreKey::DailyProtocolSideRevenue,
+                    StoreKey::ProtocolSideRevenue,
+                    "ProtocolSideRevenue",
+                ),
+            ]
+            .into_iter()
+            .for_each(|(daily_key, cumulative_key, field)| {
+                [
+                    ("TokenA", &pool.token_mint_a),
+                    ("TokenB", &pool.token_mint_b),
+                ]
+                .into_iter()
+                .for_each(|(suffix, token)| {
+                    row.set(
+                        &format!("daily{field}{suffix}"),
+                        daily_value(stores.swap_fees, daily_key.clone(), token),
+                    )
+                    .set(
+                        &format!("cumulative{field}{suffix}"),
+                        pool_value(
+                            stores.swap_fees,
+                            &swap_fees_before,
+                            cumulative_key.clone(),
+                            token,
+                        ),
+                    );
+                });
+            });
+        });
+}
+
+/// Values that the keys changed in this block held before it, taken from the first
+/// delta of each key.
+fn values_before<D: Delta, T: Clone>(
+    deltas: &Deltas<D>,
+    old_value: impl Fn(&D) -> &T,
+) -> HashMap<String, T> {
+    let mut values = HashMap::new();
+
+    deltas.deltas.iter().for_each(|delta| {
+        values
+            .entry(delta.get_key().clone())
+            .or_insert_with(|| old_value(delta).clone());
+    });
+
+    values
+}
+
+/// Value of `key` before this block: its old value if the block changed it, otherwise
+/// its current value.
+fn value_before<T: Clone>(
+    store: &impl StoreGet<T>,
+    before: &HashMap<String, T>,
+    key: &str,
+) -> Option<T> {
+    match before.get(key) {
+        Some(value) => Some(value.clone()),
+        None => store.get_last(key),
+    }
+}
+
+pub fn handle_liquidity_pool_hourly_snapshot_entity(
+    tables: &mut Tables,
+    stores: &PoolStores,
+    deltas: &PoolDeltas,
+    protocol_id: &String,
+    hour_id: i64,
+    block_number: &BigInt,
+    timestamp: &BigInt,
+) {
+    let mut snapshots: BTreeSet<(String, i64)> = BTreeSet::new();
+
+    [deltas.volume_by_token_amount, deltas.activity]
+        .into_iter()
+        .for_each(|deltas| {
+            deltas
+                .iter()
+                .key_first_segment_eq("PoolHourlySnapshot")
+                .operation_not_eq(Operation::Delete)
+                .for_each(|delta| {
+                    let hour_id = key::segment_at(&delta.key, 1)
+                        .parse::<i64>()
+                        .unwrap_or_default();
+                    let pool_address = key::segment_at(&delta.key, 3);
+
+                    snapshots.insert((pool_address.to_string(), hour_id));
+                });
+        });
+
+    deltas
+        .volume_usd
+        .iter()
+        .key_first_segment_eq("PoolHourlySnapshot")
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let hour_id = key::segment_at(&delta.key, 1)
+                .parse::<i64>()
+                .unwrap_or_default();
+            let pool_address = key::segment_at(&delta.key, 3);
+
+            snapshots.insert((pool_address.to_string(), hour_id));
+        });
+
+    // Fee collections change the balances of a pool without any activity count.
+    [deltas.balances, deltas.liquidity]
+        .into_iter()
+        .for_each(|deltas| {
+            deltas
+                .iter()
+                .operation_not_eq(Operation::Delete)
+                .for_each(|delta| {
+                    let pool_address = key::segment_at(&delta.key, 1);
+
+                    snapshots.insert((pool_address.to_string(), hour_id));
+                });
+        });
+
+    snapshots.into_iter().for_each(|(pool_address, hour_id)| {
+        let pool = match stores
+            .pools
+            .get_last(StoreKey::Pool.get_unique_key(&pool_address))
+        {
+            Some(pool) => pool,
+            None => {
+                log::info!("Pool not found: {:?}", pool_address);
+                return;
+            }
+        };
+
+        let hourly_volume = |token: &str| {
+            stores
+                .volume_by_token_amount
+                .get_last(
+                    StoreKey::PoolHourlySnapshot(
+                        hour_id,
+                        Some(Box::new(StoreKey::HourlyVolumeByTokenAmount)),
+                    )
+                    .get_unique_keys(&pool_address, token),
+                )
+                .unwrap_or_default()
+        };
+        let balance = |token: &str| {
+            stores
+                .balances
+                .get_last(StoreKey::PoolBalance.get_unique_keys(&pool_address, token))
+                .unwrap_or_default()
+        };
+
+        let hourly_count = |field: StoreKey| {
+            stores
+                .activity
+                .get_last(
+                    StoreKey::PoolHourlySnapshot(hour_id, Some(Box::new(field)))
+                        .get_unique_key(&pool_address),
+                )
+                .unwrap_or_default()
+        };
+        let cumulative_count = |field: StoreKey| {
+            stores
+                .activity
+                .get_last(field.get_unique_key(&pool_address))
+                .unwrap_or_default()
+        };
+
+        let output_token_supply = stores
+            .liquidity
+            .get_last(StoreKey::PoolLiquidity.get_unique_key(&pool_address))
+            .unwrap_or_default();
+
+        let row = tables
+            .update_row(
+                "LiquidityPoolHourlySnapshot",
+                format!("{pool_address}-{hour_id}"),
+            )
+            .set("protocol", protocol_id)
+            .set("pool", &pool_address)
+            .set("blockNumber", block_number)
+            .set("timestamp", timestamp)
+            .set(
+                "hourlyVolumeByToken0Amount",
+                hourly_volume(&pool.token_mint_a),
+            )
+            .set(
+                "hourlyVolumeByToken1Amount",
+                hourly_volume(&pool.token_mint_b),
+            )
+            .set(
+                "hourlyVolumeUSD",
+                stores
+                    .volume_usd
+                    .get_last(
+                        StoreKey::PoolHourlySnapshot(
+                            hour_id,
+                            Some(Box::new(StoreKey::HourlyVolumeUSD)),
+                        )
+                        .get_unique_key(&pool_address),
+                    )
+                    .unwrap_or_else(BigDecimal::zero),
+            )
+            .set(
+                "cumulativeVolumeUSD",
+                stores
+                    .volume_usd
+                    .get_last(StoreKey::VolumeUSD.get_unique_key(&pool_address))
+                    .unwrap_or_else(BigDecimal::zero),
+            )
+            .set(
+                "totalValueLockedUSD",
+                stores
+                    .tvl
+                    .get_last(StoreKey::PoolTotalValueLocked.get_unique_key(&pool_address))
+                    .unwrap_or_else(BigDecimal::zero),
+            )
+            .set("token0Balances", balance(&pool.token_mint_a))
+            .set("token1Balances", balance(&pool.token_mint_b))
+            .set("outputTokenSupply", &output_token_supply)
+            .set("hourlyActiveUsers", hourly_count(StoreKey::ActiveUsers))
+            .set(
+                "cumulativeUniqueUsers",
+                cumulative_count(StoreKey::CumulativeUsers),
+            );
+
+        [
+            (StoreKey::TxnCount, "TransactionCount"),
+            (StoreKey::DepositCount, "DepositCount"),
+            (StoreKey::WithdrawCount, "WithdrawCount"),
+            (StoreKey::SwapCount, "SwapCount"),
+        ]
+        .into_iter()
+        .for_each(|(count_type, field)| {
+            row.set(&format!("hourly{field}"), hourly_count(count_type.clone()))
+                .set(&format!("cumulative{field}"), cumulative_count(count_type));
+        });
+    });
+}
+
+pub fn handle_deposit_entity(
+    tables: &mut Tables,
+    map_deposits: Deposits,
+    pool_store: &StoreGetProto<Pool>,
+    positions_store: &StoreGetProto<Position>,
+    token_prices_store: &StoreGetBigDecimal,
+    protocol_id: &String,
+) {
+    map_deposits.data.iter().for_each(|deposit| {
+        let amount_usd = pool_store
+            .get_last(StoreKey::Pool.get_unique_key(&deposit.to))
+            .map(|pool| {
+                utils::amount_usd(
+                    &pool,
+                    &deposit.token_a,
+                    &deposit.amount_a,
+                    token_prices_store,
+                ) + utils::amount_usd(
+                    &pool,
+                    &deposit.token_b,
+                    &deposit.amount_b,
+                    token_prices_store,
+                )
+            })
+            .unwrap_or_else(BigDecimal::zero);
+
+        let row = tables
+            .create_row("Deposit", &deposit.id)
+            .set("blockHash", &deposit.block_hash)
+            .set("protocol", protocol_id)
+            .set("to", &deposit.to)
+            .set("from", &deposit.from)
+            .set("slot", BigInt::from(deposit.slot))
+            .set("blockNumber", BigInt::from(deposit.block_height))
+            .set("timestamp", BigInt::from(deposit.block_timestamp))
+            .set(
+                "inputTokens",
+                vec![deposit.token_a.clone(), deposit.token_b.clone()],
+            )
+            .set("outputToken", &deposit.to)
+            .set(
+                "inputTokenAmounts",
+                vec![
+                    BigInt::try_from(deposit.amount_a.clone()).unwrap(),
+                    BigInt::try_from(deposit.amount_b.clone()).unwrap(),
+                ],
+            )
+            .set(
+                "inputTokenTransferFees",
+                vec![
+                    BigInt::try_from(deposit.transfer_fee_a.clone()).unwrap_or_default(),
+                    BigInt::try_from(deposit.transfer_fee_b.clone()).unwrap_or_default(),
+                ],
+            )
+            .set(
+                "netInputTokenAmounts",
+                vec![
+                    BigInt::try_from(deposit.net_amount_a.clone()).unwrap_or_default(),
+                    BigInt::try_from(deposit.net_amount_b.clone()).unwrap_or_default(),
+                ],
+            )
+            .set(
+                "outputTokenAmount",
+                BigInt::try_from(deposit.output_amount.clone()).unwrap(),
+            )
+            .set("amountUSD", &amount_usd)
+            .set("pool", &deposit.to);
+
+        // Positions opened before the start block are not indexed.
+        if positions_store
+            .get_last(StoreKey::Position.get_unique_key(&deposit.position))
+            .is_some()
+        {
+            row.set("position", &deposit.position);
+        }
+    });
+}
+
+pub fn handle_withdraw_entity(
+    tables: &mut Tables,
+    map_withdraws: Withdraws,
+    pool_store: &StoreGetProto<Pool>,
+    positions_store: &StoreGetProto<Position>,
+    token_prices_store: &StoreGetBigDecimal,
+    protocol_id: &String,
+) {
+    map_withdraws.data.iter().for_each(|withdraw| {
+        let amount_usd = pool_store
+            .get_last(StoreKey::Pool.get_unique_key(&withdraw.to))
+            .map(|pool| {
+                utils::amount_usd(
+                    &pool,
+                    &withdraw.token_a,
+                    &withdraw.amount_a,
+                    token_prices_store,
+                ) + utils::amount_usd(
+                    &pool,
+                    &withdraw.token_b,
+                    &withdraw.amount_b,
+                    token_prices_store,
+                )
+            })
+            .unwrap_or_else(BigDecimal::zero);
+
+        let row = tables
+            .create_row("Withdraw", &withdraw.id)
+            .set("blockHash", &withdraw.block_hash)
+            .set("protocol", protocol_id)
+            .set("to", &withdraw.to)
+            .set("from", &withdraw.from)
+            .set("slot", BigInt::from(withdraw.slot))
+            .set("blockNumber", BigInt::from(withdraw.block_height))
             .set("timestamp", BigInt::from(withdraw.block_timestamp))
             .set(
                 "inputTokens",
@@ -334,18 +1527,55 @@ pub fn handle_withdraw_entity(
                     BigInt::try_from(withdraw.amount_b.clone()).unwrap(),
                 ],
             )
+            .set(
+                "inputTokenTransferFees",
+                vec![
+                    BigInt::try_from(withdraw.transfer_fee_a.clone()).unwrap_or_default(),
+                    BigInt::try_from(withdraw.transfer_fee_b.clone()).unwrap_or_default(),
+                ],
+            )
+            .set(
+                "netInputTokenAmounts",
+                vec![
+                    BigInt::try_from(withdraw.net_amount_a.clone()).unwrap_or_default(),
+                    BigInt::try_from(withdraw.net_amount_b.clone()).unwrap_or_default(),
+                ],
+            )
             .set(
                 "outputTokenAmount",
                 BigInt::try_from(withdraw.output_amount.clone()).unwrap(),
             )
-            .set("amountUSD", &BigDecimal::zero())
+            .set("amountUSD", &amount_usd)
             .set("pool", &withdraw.to);
+
+        // Positions opened before the start block are not indexed.
+        if positions_store
+            .get_last(StoreKey::Position.get_unique_key(&withdraw.position))
+            .is_some()
+        {
+            row.set("position", &withdraw.position);
+        }
     });
 }
 
-pub fn handle_swap_entity(tables: &mut Tables, map_swaps: Swaps, protocol_id: &String) {
+pub fn handle_swap_entity(
+    tables: &mut Tables,
+    map_swaps: Swaps,
+    pool_store: &StoreGetProto<Pool>,
+    token_prices_store: &StoreGetBigDecimal,
+    protocol_id: &String,
+) {
     map_swaps.data.iter().for_each(|swap| {
-        tables
+        let (amount_in_usd, amount_out_usd) =
+            match pool_store.get_last(StoreKey::Pool.get_unique_key(&swap.to)) {
+                Some(pool) => (
+                    utils::amount_usd(&pool, &swap.token_in, &swap.amount_in, token_prices_store),
+                    utils::amount_usd(&pool, &swap.token_out, &swap.amount_out, token_prices_store),
+                ),
+                None => (BigDecimal::zero(), BigDecimal::zero()),
+            };
+
+        let row = tables
             .create_row("Swap", &swap.id)
             .set("blockHash", &swap.block_hash)
             .set("protocol", protocol_id)
@@ -356,10 +1586,516 @@ pub fn handle_swap_entity(tables: &mut Tables, map_swaps: Swaps, protocol_id: &S
             .set("timestamp", BigInt::from(swap.block_timestamp))
             .set("tokenIn", &swap.token_in)
             .set_bigint("amountIn", &swap.amount_in)
-            .set("amountInUSD", &BigDecimal::zero())
+            .set_bigint("amountInTransferFee", &swap.transfer_fee_in)
+            .set_bigint("netAmountIn", &swap.net_amount_in)
+            .set("amountInUSD", &amount_in_usd)
             .set("tokenOut", &swap.token_out)
             .set_bigint("amountOut", &swap.amount_out)
-            .set("amountOutUSD", &BigDecimal::zero())
+            .set_bigint("amountOutTransferFee", &swap.transfer_fee_out)
+            .set_bigint("netAmountOut", &swap.net_amount_out)
+            .set("amountOutUSD", &amount_out_usd)
+            .set_bigint("feeAmount", &swap.fee_amount)
+            .set_bigint("lpFeeAmount", &swap.lp_fee_amount)
+            .set_bigint("protocolFeeAmount", &swap.protocol_fee_amount)
             .set("pool", &swap.to);
+
+        if let Some(price) = &swap.price {
+            set_pool_price(row, price);
+        }
+    });
+}
+
+pub fn handle_account_entity(
+    tables: &mut Tables,
+    unique_users_deltas: &Deltas<DeltaBigInt>,
+    account_activity_deltas: &Deltas<DeltaBigInt>,
+    timestamp: &BigInt,
+) {
+    let bigint0 = BigInt::zero();
+
+    // The user key of an account holds the block it was first seen in.
+    unique_users_deltas
+        .iter()
+        .key_first_segment_eq(StoreKey::User.unique_id())
+        .operation_eq(Operation::Create)
+        .for_each(|delta| {
+            tables
+                .create_row("Account", key::segment_at(&delta.key, 1))
+                .set("firstSeenBlockNumber", &delta.new_value)
+                .set("firstSeenTimestamp", timestamp)
+                .set("swapCount", &bigint0)
+                .set("depositCount", &bigint0)
+                .set("withdrawCount", &bigint0);
+        });
+
+    account_activity_deltas
+        .iter()
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let account = key::segment_at(&delta.key, 1);
+
+            let field = match key::first_segment(&delta.key) {
+                x if x == StoreKey::AccountSwapCount.unique_id() => "swapCount",
+                x if x == StoreKey::AccountDepositCount.unique_id() => "depositCount",
+                x if x == StoreKey::AccountWithdrawCount.unique_id() => "withdrawCount",
+                x if x == StoreKey::AccountVolumeByTokenAmount.unique_id() => {
+                    let token = key::segment_at(&delta.key, 2);
+                    let id = format!("{account}-{token}");
+
+                    let row = if delta.operation == Operation::Create {
+                        tables
+                            .create_row("AccountTokenVolume", id)
+                            .set("account", account)
+                            .set("token", token)
+                    } else {
+                        tables.update_row("AccountTokenVolume", id)
+                    };
+                    row.set("cumulativeVolumeTokenAmount", &delta.new_value);
+
+                    return;
+                }
+                _ => return,
+            };
+
+            tables
+                .update_row("Account", account)
+                .set(field, &delta.new_value);
+        });
+}
+
+pub fn handle_fee_collection_entity(
+    tables: &mut Tables,
+    map_fee_collections: FeeCollections,
+    protocol_id: &String,
+) {
+    map_fee_collections.data.iter().for_each(|fee_collection| {
+        tables
+            .create_row("FeeCollection", &fee_collection.id)
+            .set("blockHash", &fee_collection.block_hash)
+            .set("protocol", protocol_id)
+            .set("to", &fee_collection.to)
+            .set("from", &fee_collection.from)
+            .set("slot", BigInt::from(fee_collection.slot))
+            .set("blockNumber", BigInt::from(fee_collection.block_height))
+            .set("timestamp", BigInt::from(fee_collection.block_timestamp))
+            .set("position", &fee_collection.position)
+            .set(
+                "inputTokens",
+                vec![
+                    fee_collection.token_a.clone(),
+                    fee_collection.token_b.clone(),
+                ],
+            )
+            .set(
+                "inputTokenAmounts",
+                vec![
+                    BigInt::try_from(fee_collection.amount_a.clone()).unwrap(),
+                    BigInt::try_from(fee_collection.amount_b.clone()).unwrap(),
+                ],
+            )
+            .set("pool", &fee_collection.pool);
+    });
+}
+
+pub fn handle_position_entity(
+    tables: &mut Tables,
+    map_positions: &Positions,
+    stores: &PositionStores,
+    deltas: &PositionDeltas,
+    protocol_id: &String,
+    block_number: &BigInt,
+    timestamp: &BigInt,
+) {
+    map_positions.bundles.iter().for_each(|bundle| {
+        tables
+            .create_row("PositionBundle", &bundle.address)
+            .set("positionBundleMint", &bundle.position_bundle_mint)
+            .set("owner", &bundle.owner)
+            .set("createdBlockNumber", BigInt::from(bundle.block_height))
+            .set("createdTimestamp", BigInt::from(bundle.block_timestamp));
+    });
+
+    map_positions.data.iter().for_each(|position| {
+        if position.is_closed {
+            if stores
+                .positions
+                .get_last(StoreKey::Position.get_unique_key(&position.address))
+                .is_none()
+            {
+                log::info!("Position not found: {:?}", position.address);
+                return;
+            }
+
+            tables
+                .update_row("Position", &position.address)
+                .set("closedBlockNumber", BigInt::from(position.block_height))
+                .set("closedTimestamp", BigInt::from(position.block_timestamp));
+
+            return;
+        }
+
+        let row = tables
+            .create_row("Position", &position.address)
+            .set("protocol", protocol_id)
+            .set("pool", &position.whirlpool)
+            .set("owner", &position.owner)
+            .set("tickLower", BigInt::from(position.tick_lower_index))
+            .set("tickUpper", BigInt::from(position.tick_upper_index))
+            .set("openedBlockNumber", BigInt::from(position.block_height))
+            .set("openedTimestamp", BigInt::from(position.block_timestamp));
+
+        // A reopened bundled position reuses the account of a closed one, so clear the
+        // close of its previous lifecycle. Its liquidity and counters were reset by their
+        // stores.
+        for field in ["closedBlockNumber", "closedTimestamp"] {
+            row.columns.insert(field.to_string(), Value { typed: None });
+        }
+
+        if !position.position_mint.is_empty() {
+            row.set("positionMint", &position.position_mint);
+        }
+
+        if let (Some(position_bundle), Some(bundle_index)) =
+            (&position.position_bundle, position.bundle_index)
+        {
+            row.set("positionBundle", position_bundle)
+                .set("bundleIndex", bundle_index as i32);
+        }
+
+        if let Some(pool) = stores
+            .pools
+            .get_last(StoreKey::Pool.get_unique_key(&position.whirlpool))
+        {
+            set_position_liquidity(row, &position.address, &pool, stores.liquidity);
+        }
+    });
+
+    // Follow the position NFT to its current holder.
+    deltas
+        .owners
+        .iter()
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let position_address = key::segment_at(&delta.key, 1);
+            if stores
+                .positions
+                .get_last(StoreKey::Position.get_unique_key(position_address))
+                .is_none()
+            {
+                return;
+            }
+
+            tables
+                .update_row("Position", position_address)
+                .set("owner", &delta.new_value);
+        });
+
+    // Update every position whose liquidity changed in this block, and snapshot it.
+    let mut changed_positions: BTreeSet<String> = BTreeSet::new();
+
+    deltas
+        .liquidity
+        .iter()
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            changed_positions.insert(key::segment_at(&delta.key, 1).to_string());
+        });
+
+    changed_positions.into_iter().for_each(|position_address| {
+        // Positions opened before the start block are not indexed.
+        let position = match stores
+            .positions
+            .get_last(StoreKey::Position.get_unique_key(&position_address))
+        {
+            Some(position) => position,
+            None => return,
+        };
+        let pool = match stores
+            .pools
+            .get_last(StoreKey::Pool.get_unique_key(&position.whirlpool))
+        {
+            Some(pool) => pool,
+            None => {
+                log::info!("Pool not found: {:?}", position.whirlpool);
+                return;
+            }
+        };
+
+        set_position_liquidity(
+            tables.update_row("Position", &position_address),
+            &position_address,
+            &pool,
+            stores.liquidity,
+        );
+
+        let row = tables
+            .create_row(
+                "PositionSnapshot",
+                format!("{position_address}-{block_number}"),
+            )
+            .set("position", &position_address)
+            .set("pool", &position.whirlpool)
+            .set("blockNumber", block_number)
+            .set("timestamp", timestamp);
+
+        set_position_liquidity(row, &position_address, &pool, stores.liquidity);
+    });
+}
+
+fn set_position_liquidity(
+    row: &mut Row,
+    position_address: &str,
+    pool: &Pool,
+    position_liquidity_store: &StoreGetBigInt,
+) {
+    let value = |key: String| position_liquidity_store.get_last(key).unwrap_or_default();
+
+    row.set(
+        "liquidity",
+        value(StoreKey::PositionLiquidity.get_unique_key(position_address)),
+    )
+    .set(
+        "depositCount",
+        value(StoreKey::PositionDepositCount.get_unique_key(position_address)),
+    )
+    .set(
+        "withdrawCount",
+        value(StoreKey::PositionWithdrawCount.get_unique_key(position_address)),
+    )
+    .set(
+        "cumulativeDepositedToken0Amount",
+        value(
+            StoreKey::PositionDepositedAmount.get_unique_keys(position_address, &pool.token_mint_a),
+        ),
+    )
+    .set(
+        "cumulativeDepositedToken1Amount",
+        value(
+            StoreKey::PositionDepositedAmount.get_unique_keys(position_address, &pool.token_mint_b),
+        ),
+    )
+    .set(
+        "cumulativeWithdrawnToken0Amount",
+        value(
+            StoreKey::PositionWithdrawnAmount.get_unique_keys(position_address, &pool.token_mint_a),
+        ),
+    )
+    .set(
+        "cumulativeWithdrawnToken1Amount",
+        value(
+            StoreKey::PositionWithdrawnAmount.get_unique_keys(position_address, &pool.token_mint_b),
+        ),
+    );
+}
+
+pub fn handle_reward_entity(
+    tables: &mut Tables,
+    map_rewards: &Rewards,
+    reward_tokens_store: &StoreGetProto<RewardToken>,
+    collected_rewards_deltas: &Deltas<DeltaBigInt>,
+    protocol_id: &String,
+) {
+    map_rewards.tokens.iter().for_each(|reward_token| {
+        tables
+            .create_row("RewardToken", &reward_token.id)
+            .set("protocol", protocol_id)
+            .set("pool", &reward_token.pool)
+            .set("rewardIndex", reward_token.reward_index as i32)
+            .set("mint", &reward_token.mint)
+            .set("vault", &reward_token.vault)
+            .set("authority", &reward_token.authority)
+            .set("tokenProgram", &reward_token.token_program)
+            .set("emissionsPerSecondX64", BigInt::zero())
+            .set("cumulativeCollectedAmount", BigInt::zero())
+            .set(
+                "createdBlockNumber",
+                BigInt::from(reward_token.block_height),
+            )
+            .set(
+                "createdTimestamp",
+                BigInt::from(reward_token.block_timestamp),
+            );
+    });
+
+    map_rewards.emissions.iter().for_each(|emissions| {
+        // Reward tokens initialised before the start block have no entity to update.
+        if reward_tokens_store
+            .get_last(StoreKey::RewardToken.get_unique_key(&emissions.reward_token))
+            .is_none()
+        {
+            log::info!("Reward token not found: {:?}", emissions.reward_token);
+            return;
+        }
+
+        let emissions_per_second_x64 =
+            BigInt::try_from(emissions.emissions_per_second_x64.clone()).unwrap_or_default();
+
+        tables
+            .update_row("RewardToken", &emissions.reward_token)
+            .set("emissionsPerSecondX64", &emissions_per_second_x64);
+
+        tables
+            .create_row("RewardEmissionsChange", &emissions.id)
+            .set("rewardToken", &emissions.reward_token)
+            .set("pool", &emissions.pool)
+            .set("emissionsPerSecondX64", &emissions_per_second_x64)
+            .set("authority", &emissions.authority)
+            .set("hash", &emissions.txn_id)
+            .set("blockNumber", BigInt::from(emissions.block_height))
+            .set("timestamp", BigInt::from(emissions.block_timestamp));
+    });
+
+    map_rewards.collections.iter().for_each(|collection| {
+        if reward_tokens_store
+            .get_last(StoreKey::RewardToken.get_unique_key(&collection.reward_token))
+            .is_none()
+        {
+            log::info!("Reward token not found: {:?}", collection.reward_token);
+            return;
+        }
+
+        tables
+            .create_row("RewardCollection", &collection.id)
+            .set("blockHash", &collection.block_hash)
+            .set("protocol", protocol_id)
+            .set("to", &collection.to)
+            .set("from", &collection.from)
+            .set("slot", BigInt::from(collection.slot))
+            .set("blockNumber", BigInt::from(collection.block_height))
+            .set("timestamp", BigInt::from(collection.block_timestamp))
+            .set("pool", &collection.pool)
+            .set("position", &collection.position)
+            .set("rewardToken", &collection.reward_token)
+            .set(
+                "amount",
+                BigInt::try_from(collection.amount.clone()).unwrap_or_default(),
+            );
+    });
+
+    collected_rewards_deltas
+        .iter()
+        .key_first_segment_eq(StoreKey::CollectedRewards.unique_id())
+        .for_each(|delta| {
+            let reward_token = key::segment_at(&delta.key, 1);
+
+            if reward_tokens_store
+                .get_last(StoreKey::RewardToken.get_unique_key(reward_token))
+                .is_none()
+            {
+                return;
+            }
+
+            tables
+                .update_row("RewardToken", reward_token)
+                .set("cumulativeCollectedAmount", &delta.new_value);
+        });
+
+    collected_rewards_deltas
+        .iter()
+        .key_first_segment_eq(StoreKey::PositionCollectedRewards.unique_id())
+        .for_each(|delta| {
+            let position = key::segment_at(&delta.key, 1);
+            let reward_token = key::segment_at(&delta.key, 2);
+
+            if reward_tokens_store
+                .get_last(StoreKey::RewardToken.get_unique_key(reward_token))
+                .is_none()
+            {
+                return;
+            }
+
+            // Keys are deleted when a bundled position is reopened, which starts it over.
+            let cumulative_collected_amount = match delta.operation {
+                Operation::Delete => BigInt::zero(),
+                _ => delta.new_value.clone(),
+            };
+
+            tables
+                .update_row("PositionReward", format!("{}-{}", position, reward_token))
+                .set("position", position)
+                .set("rewardToken", reward_token)
+                .set("cumulativeCollectedAmount", cumulative_collected_amount);
+        });
+}
+
+pub fn handle_config_entity(
+    tables: &mut Tables,
+    map_configs: &Configs,
+    configs_store: &StoreGetInt64,
+    protocol_id: &String,
+) {
+    map_configs.configs.iter().for_each(|config| {
+        tables
+            .create_row("WhirlpoolsConfig", &config.address)
+            .set("protocol", protocol_id)
+            .set("feeAuthority", &config.fee_authority)
+            .set(
+                "collectProtocolFeesAuthority",
+                &config.collect_protocol_fees_authority,
+            )
+            .set(
+                "rewardEmissionsSuperAuthority",
+                &config.reward_emissions_super_authority,
+            )
+            .set(
+                "defaultProtocolFeeRate",
+                config.default_protocol_fee_rate as i32,
+            )
+            .set("createdBlockNumber", BigInt::from(config.block_height))
+            .set("createdTimestamp", BigInt::from(config.block_timestamp));
+    });
+
+    map_configs.fee_tiers.iter().for_each(|fee_tier| {
+        tables
+            .create_row("FeeTier", &fee_tier.address)
+            .set("whirlpoolsConfig", &fee_tier.whirlpools_config)
+            .set("tickSpacing", fee_tier.tick_spacing as i32)
+            .set("defaultFeeRate", fee_tier.default_fee_rate as i32)
+            .set("createdBlockNumber", BigInt::from(fee_tier.block_height))
+            .set("createdTimestamp", BigInt::from(fee_tier.block_timestamp));
+    });
+
+    map_configs.fee_rate_changes.iter().for_each(|change| {
+        let row = tables
+            .create_row("FeeRateChange", &change.id)
+            .set("kind", &change.kind)
+            .set("whirlpoolsConfig", &change.whirlpools_config)
+            .set("rate", change.rate as i32)
+            .set("authority", &change.authority)
+            .set("hash", &change.txn_id)
+            .set("blockNumber", BigInt::from(change.block_height))
+            .set("timestamp", BigInt::from(change.block_timestamp));
+
+        if let Some(fee_tier) = &change.fee_tier {
+            row.set("feeTier", fee_tier);
+        }
+        if let Some(pool) = &change.pool {
+            row.set("pool", pool);
+        }
+
+        // Pool fee rates are kept up to date from the pool fee rates store.
+        match change.kind.as_str() {
+            "DEFAULT_FEE_RATE" => {
+                let fee_tier = change.fee_tier.clone().unwrap_or_default();
+                if configs_store
+                    .get_last(StoreKey::FeeTier.get_unique_key(&fee_tier))
+                    .is_some()
+                {
+                    tables
+                        .update_row("FeeTier", &fee_tier)
+                        .set("defaultFeeRate", change.rate as i32);
+                }
+            }
+            "DEFAULT_PROTOCOL_FEE_RATE"
+                if configs_store
+                    .get_last(StoreKey::WhirlpoolsConfig.get_unique_key(&change.whirlpools_config))
+                    .is_some() =>
+            {
+                tables
+                    .update_row("WhirlpoolsConfig", &change.whirlpools_config)
+                    .set("defaultProtocolFeeRate", change.rate as i32);
+            }
+            _ => {}
+        }
     });
 }
@@ -0,0 +1,41 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{close_bundled_position, CloseBundledPosition};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct CloseBundledPositionInstruction {
+    // The index of the slot in the position bundle used by this position.
+    pub bundle_index: u16,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct CloseBundledPositionInstructionAccounts<'a> {
+    pub bundled_position: Address<'a>,
+    pub position_bundle: Address<'a>,
+    pub position_bundle_token_account: Address<'a>,
+    pub position_bundle_authority: Address<'a>,
+    pub receiver: Address<'a>,
+}
+
+pub fn process_close_bundled_position(
+    data: CloseBundledPositionInstruction,
+    input_accounts: CloseBundledPositionInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::CloseBundledPosition(CloseBundledPosition {
+        instruction: Some(close_bundled_position::Instruction {
+            bundle_index: data.bundle_index as u32,
+        }),
+        accounts: Some(close_bundled_position::Accounts {
+            bundled_position: input_accounts.bundled_position.to_string(),
+            position_bundle: input_accounts.position_bundle.to_string(),
+            position_bundle_token_account: input_accounts.position_bundle_token_account.to_string(),
+            position_bundle_authority: input_accounts.position_bundle_authority.to_string(),
+            receiver: input_accounts.receiver.to_string(),
+        }),
+    }))
+}
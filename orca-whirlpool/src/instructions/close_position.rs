@@ -0,0 +1,37 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{close_position, ClosePosition};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct ClosePositionInstruction {}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct ClosePositionInstructionAccounts<'a> {
+    pub position_authority: Address<'a>,
+    pub receiver: Address<'a>,
+    pub position: Address<'a>,
+    pub position_mint: Address<'a>,
+    pub position_token_account: Address<'a>,
+    pub token_program: Address<'a>,
+}
+
+pub fn process_close_position(
+    _data: ClosePositionInstruction,
+    input_accounts: ClosePositionInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::ClosePosition(ClosePosition {
+        accounts: Some(close_position::Accounts {
+            position_authority: input_accounts.position_authority.to_string(),
+            receiver: input_accounts.receiver.to_string(),
+            position: input_accounts.position.to_string(),
+            position_mint: input_accounts.position_mint.to_string(),
+            position_token_account: input_accounts.position_token_account.to_string(),
+            token_program: input_accounts.token_program.to_string(),
+        }),
+    }))
+}
@@ -0,0 +1,60 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{collect_fees, CollectFees};
+use crate::traits::account_deserialize::AccountsDeserialize;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
+use crate::utils;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct CollectFeesInstruction {}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct CollectFeesInstructionAccounts<'a> {
+    pub whirlpool: Address<'a>,
+    pub position_authority: Address<'a>,
+    pub position: Address<'a>,
+    pub position_token_account: Address<'a>,
+    pub token_owner_account_a: Address<'a>,
+    pub token_vault_a: Address<'a>,
+    pub token_owner_account_b: Address<'a>,
+    pub token_vault_b: Address<'a>,
+    pub token_program: Address<'a>,
+}
+
+pub fn process_collect_fees(
+    _data: CollectFeesInstruction,
+    input_accounts: CollectFeesInstructionAccounts,
+    balances: &InstructionBalances,
+) -> Option<Type> {
+    let (token_a_pre_bal, token_a_post_bal) =
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_a);
+    let (token_b_pre_bal, token_b_post_bal) =
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_b);
+
+    Some(Type::CollectFees(CollectFees {
+        instruction: Some(collect_fees::Instruction {
+            amount_a: utils::balance_difference(token_a_pre_bal.clone(), token_a_post_bal.clone()),
+            amount_a_pre: token_a_pre_bal.clone(),
+            amount_a_post: token_a_post_bal.clone(),
+
+            amount_b: utils::balance_difference(token_b_pre_bal.clone(), token_b_post_bal.clone()),
+            amount_b_pre: token_b_pre_bal.clone(),
+            amount_b_post: token_b_post_bal.clone(),
+        }),
+        accounts: Some(collect_fees::Accounts {
+            whirlpool: input_accounts.whirlpool.to_string(),
+            position_authority: input_accounts.position_authority.to_string(),
+            position: input_accounts.position.to_string(),
+            position_token_account: input_accounts.position_token_account.to_string(),
+            token_owner_account_a: input_accounts.token_owner_account_a.to_string(),
+            token_vault_a: input_accounts.token_vault_a.to_string(),
+            token_owner_account_b: input_accounts.token_owner_account_b.to_string(),
+            token_vault_b: input_accounts.token_vault_b.to_string(),
+            token_program: input_accounts.token_program.to_string(),
+        }),
+    }))
+}
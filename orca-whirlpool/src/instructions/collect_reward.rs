@@ -0,0 +1,55 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{collect_reward, CollectReward};
+use crate::traits::account_deserialize::AccountsDeserialize;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
+use crate::utils;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct CollectRewardInstruction {
+    // The reward slot of the Whirlpool (0, 1 or 2) to collect from.
+    pub reward_index: u8,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct CollectRewardInstructionAccounts<'a> {
+    pub whirlpool: Address<'a>,
+    pub position_authority: Address<'a>,
+    pub position: Address<'a>,
+    pub position_token_account: Address<'a>,
+    pub reward_owner_account: Address<'a>,
+    pub reward_vault: Address<'a>,
+    pub token_program: Address<'a>,
+}
+
+pub fn process_collect_reward(
+    data: CollectRewardInstruction,
+    input_accounts: CollectRewardInstructionAccounts,
+    balances: &InstructionBalances,
+) -> Option<Type> {
+    let (reward_pre_bal, reward_post_bal) =
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.reward_vault);
+
+    Some(Type::CollectReward(CollectReward {
+        instruction: Some(collect_reward::Instruction {
+            reward_index: data.reward_index as u32,
+
+            amount: utils::balance_difference(reward_pre_bal.clone(), reward_post_bal.clone()),
+            amount_pre: reward_pre_bal.clone(),
+            amount_post: reward_post_bal.clone(),
+        }),
+        accounts: Some(collect_reward::Accounts {
+            whirlpool: input_accounts.whirlpool.to_string(),
+            position_authority: input_accounts.position_authority.to_string(),
+            position: input_accounts.position.to_string(),
+            position_token_account: input_accounts.position_token_account.to_string(),
+            reward_owner_account: input_accounts.reward_owner_account.to_string(),
+            reward_vault: input_accounts.reward_vault.to_string(),
+            token_program: input_accounts.token_program.to_string(),
+        }),
+    }))
+}
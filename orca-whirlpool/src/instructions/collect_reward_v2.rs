@@ -0,0 +1,63 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{collect_reward_v2, CollectRewardV2};
+use crate::traits::account_deserialize::AccountsDeserialize;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
+use crate::utils;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+use super::utils::RemainingAccountsInfo;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct CollectRewardInstructionV2 {
+    // The reward slot of the Whirlpool (0, 1 or 2) to collect from.
+    pub reward_index: u8,
+    // Remaining accounts info
+    pub remaining_accounts_info: Option<RemainingAccountsInfo>,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct CollectRewardInstructionAccountsV2<'a> {
+    pub whirlpool: Address<'a>,
+    pub position_authority: Address<'a>,
+    pub position: Address<'a>,
+    pub position_token_account: Address<'a>,
+    pub reward_owner_account: Address<'a>,
+    pub reward_mint: Address<'a>,
+    pub reward_vault: Address<'a>,
+    pub reward_token_program: Address<'a>,
+    pub memo_program: Address<'a>,
+}
+
+pub fn process_collect_reward_v2(
+    data: CollectRewardInstructionV2,
+    input_accounts: CollectRewardInstructionAccountsV2,
+    balances: &InstructionBalances,
+) -> Option<Type> {
+    let (reward_pre_bal, reward_post_bal) =
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.reward_vault);
+
+    Some(Type::CollectRewardV2(CollectRewardV2 {
+        instruction: Some(collect_reward_v2::Instruction {
+            reward_index: data.reward_index as u32,
+
+            amount: utils::balance_difference(reward_pre_bal.clone(), reward_post_bal.clone()),
+            amount_pre: reward_pre_bal.clone(),
+            amount_post: reward_post_bal.clone(),
+        }),
+        accounts: Some(collect_reward_v2::Accounts {
+            whirlpool: input_accounts.whirlpool.to_string(),
+            position_authority: input_accounts.position_authority.to_string(),
+            position: input_accounts.position.to_string(),
+            position_token_account: input_accounts.position_token_account.to_string(),
+            reward_owner_account: input_accounts.reward_owner_account.to_string(),
+            reward_mint: input_accounts.reward_mint.to_string(),
+            reward_vault: input_accounts.reward_vault.to_string(),
+            reward_token_program: input_accounts.reward_token_program.to_string(),
+            memo_program: input_accounts.memo_program.to_string(),
+        }),
+    }))
+}
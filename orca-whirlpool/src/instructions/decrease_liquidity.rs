@@ -1,13 +1,12 @@
 use crate::pb::messari::orca_whirlpool::v1::event::Type;
 use crate::pb::messari::orca_whirlpool::v1::{decrease_liquidity, DecreaseLiquidity};
 use crate::traits::account_deserialize::AccountsDeserialize;
-use crate::traits::balance_of::BalanceOf;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
 use crate::utils;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use derive_deserialize::AccountsDeserialize;
 use substreams_solana::block_view::InstructionView;
-use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::Address;
 
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
@@ -38,12 +37,12 @@ pub struct DecreaseLiquidityInstructionAccounts<'a> {
 pub fn process_decrease_liquidity(
     data: DecreaseLiquidityInstruction,
     input_accounts: DecreaseLiquidityInstructionAccounts,
-    confirmed_txn: &ConfirmedTransaction,
+    balances: &InstructionBalances,
 ) -> Option<Type> {
     let (token_a_pre_bal, token_a_post_bal) =
-        confirmed_txn.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_a);
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_a);
     let (token_b_pre_bal, token_b_post_bal) =
-        confirmed_txn.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_b);
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_b);
 
     Some(Type::DecreaseLiquidity(DecreaseLiquidity {
         instruction: Some(decrease_liquidity::Instruction {
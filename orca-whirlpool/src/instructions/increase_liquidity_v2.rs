@@ -1,13 +1,12 @@
 use crate::pb::messari::orca_whirlpool::v1::event::Type;
 use crate::pb::messari::orca_whirlpool::v1::{increase_liquidity_v2, IncreaseLiquidityV2};
 use crate::traits::account_deserialize::AccountsDeserialize;
-use crate::traits::balance_of::BalanceOf;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
 use crate::utils;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use derive_deserialize::AccountsDeserialize;
 use substreams_solana::block_view::InstructionView;
-use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::Address;
 
 use super::utils::RemainingAccountsInfo;
@@ -46,12 +45,12 @@ pub struct IncreaseLiquidityInstructionAccountsV2<'a> {
 pub fn process_increase_liquidity_v2(
     data: IncreaseLiquidityInstructionV2,
     input_accounts: IncreaseLiquidityInstructionAccountsV2,
-    confirmed_txn: &ConfirmedTransaction,
+    balances: &InstructionBalances,
 ) -> Option<Type> {
     let (token_a_pre_bal, token_a_post_bal) =
-        confirmed_txn.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_a);
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_a);
     let (token_b_pre_bal, token_b_post_bal) =
-        confirmed_txn.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_b);
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_b);
 
     Some(Type::IncreaseLiquidityV2(IncreaseLiquidityV2 {
         instruction: Some(increase_liquidity_v2::Instruction {
@@ -67,6 +66,9 @@ pub fn process_increase_liquidity_v2(
             amount_b: utils::balance_difference(token_b_pre_bal.clone(), token_b_post_bal.clone()),
             amount_b_pre: token_b_pre_bal.clone(),
             amount_b_post: token_b_post_bal.clone(),
+
+            transfer_fee_a: balances.transfer_fee_of(&input_accounts.token_vault_a),
+            transfer_fee_b: balances.transfer_fee_of(&input_accounts.token_vault_b),
         }),
         accounts: Some(increase_liquidity_v2::Accounts {
             whirlpool: input_accounts.whirlpool.to_string(),
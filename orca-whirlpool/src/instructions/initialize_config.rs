@@ -0,0 +1,48 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{initialize_config, InitializeConfig};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct InitializeConfigInstruction {
+    // Authority allowed to change the fee rates of the config and its pools.
+    pub fee_authority: [u8; 32],
+    // Authority allowed to collect the protocol fees of the config's pools.
+    pub collect_protocol_fees_authority: [u8; 32],
+    // Authority allowed to set the reward authorities of the config's pools.
+    pub reward_emissions_super_authority: [u8; 32],
+    // The protocol fee rate assigned to new pools, in basis points of the fee.
+    pub default_protocol_fee_rate: u16,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct InitializeConfigInstructionAccounts<'a> {
+    pub config: Address<'a>,
+    pub funder: Address<'a>,
+    pub system_program: Address<'a>,
+}
+
+pub fn process_initialize_config(
+    data: InitializeConfigInstruction,
+    input_accounts: InitializeConfigInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::InitializeConfig(InitializeConfig {
+        instruction: Some(initialize_config::Instruction {
+            fee_authority: bs58::encode(data.fee_authority).into_string(),
+            collect_protocol_fees_authority: bs58::encode(data.collect_protocol_fees_authority)
+                .into_string(),
+            reward_emissions_super_authority: bs58::encode(data.reward_emissions_super_authority)
+                .into_string(),
+            default_protocol_fee_rate: data.default_protocol_fee_rate as u32,
+        }),
+        accounts: Some(initialize_config::Accounts {
+            config: input_accounts.config.to_string(),
+            funder: input_accounts.funder.to_string(),
+            system_program: input_accounts.system_program.to_string(),
+        }),
+    }))
+}
@@ -0,0 +1,44 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{initialize_fee_tier, InitializeFeeTier};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct InitializeFeeTierInstruction {
+    // The tick spacing of the pools using this fee tier.
+    pub tick_spacing: u16,
+    // The fee rate assigned to new pools, in hundredths of a basis point.
+    pub default_fee_rate: u16,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct InitializeFeeTierInstructionAccounts<'a> {
+    pub config: Address<'a>,
+    pub fee_tier: Address<'a>,
+    pub funder: Address<'a>,
+    pub fee_authority: Address<'a>,
+    pub system_program: Address<'a>,
+}
+
+pub fn process_initialize_fee_tier(
+    data: InitializeFeeTierInstruction,
+    input_accounts: InitializeFeeTierInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::InitializeFeeTier(InitializeFeeTier {
+        instruction: Some(initialize_fee_tier::Instruction {
+            tick_spacing: data.tick_spacing as u32,
+            default_fee_rate: data.default_fee_rate as u32,
+        }),
+        accounts: Some(initialize_fee_tier::Accounts {
+            config: input_accounts.config.to_string(),
+            fee_tier: input_accounts.fee_tier.to_string(),
+            funder: input_accounts.funder.to_string(),
+            fee_authority: input_accounts.fee_authority.to_string(),
+            system_program: input_accounts.system_program.to_string(),
+        }),
+    }))
+}
@@ -1,6 +1,7 @@
 use crate::pb::messari::orca_whirlpool::v1::event::Type;
 use crate::pb::messari::orca_whirlpool::v1::{initialize_pool, InitializePool};
 use crate::traits::account_deserialize::AccountsDeserialize;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use derive_deserialize::AccountsDeserialize;
@@ -37,12 +38,16 @@ pub struct InitializePoolInstructionAccounts<'a> {
 pub fn process_initialize_pool(
     data: InitializePoolInstruction,
     input_accounts: InitializePoolInstructionAccounts,
+    balances: &InstructionBalances,
 ) -> Option<Type> {
     Some(Type::InitializePool(InitializePool {
         instruction: Some(initialize_pool::Instruction {
             bumps: data.bumps.whirlpool_bump as u32,
             tick_spacing: data.tick_spacing as u32,
             initial_sqrt_price: data.initial_sqrt_price.to_string(),
+
+            decimals_a: balances.decimals_of(&input_accounts.token_mint_a),
+            decimals_b: balances.decimals_of(&input_accounts.token_mint_b),
         }),
         accounts: Some(initialize_pool::Accounts {
             whirlpools_config: input_accounts.whirlpools_config.to_string(),
@@ -1,17 +1,13 @@
 use crate::pb::messari::orca_whirlpool::v1::event::Type;
 use crate::pb::messari::orca_whirlpool::v1::{initialize_pool_v2, InitializePoolV2};
 use crate::traits::account_deserialize::AccountsDeserialize;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use derive_deserialize::AccountsDeserialize;
 use substreams_solana::block_view::InstructionView;
 use substreams_solana::Address;
 
-#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
-pub struct WhirlpoolBumps {
-    pub whirlpool_bump: u8,
-}
-
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
 pub struct InitializePoolInstructionV2 {
     // The desired tick spacing for this pool.
@@ -41,16 +37,22 @@ pub struct InitializePoolInstructionAccountsV2<'a> {
 pub fn process_initialize_pool_v2(
     data: InitializePoolInstructionV2,
     input_accounts: InitializePoolInstructionAccountsV2,
+    balances: &InstructionBalances,
 ) -> Option<Type> {
     Some(Type::InitializePoolV2(InitializePoolV2 {
         instruction: Some(initialize_pool_v2::Instruction {
             tick_spacing: data.tick_spacing as u32,
             initial_sqrt_price: data.initial_sqrt_price.to_string(),
+
+            decimals_a: balances.decimals_of(&input_accounts.token_mint_a),
+            decimals_b: balances.decimals_of(&input_accounts.token_mint_b),
         }),
         accounts: Some(initialize_pool_v2::Accounts {
             whirlpools_config: input_accounts.whirlpools_config.to_string(),
             token_mint_a: input_accounts.token_mint_a.to_string(),
             token_mint_b: input_accounts.token_mint_b.to_string(),
+            token_badge_a: input_accounts.token_badge_a.to_string(),
+            token_badge_b: input_accounts.token_badge_b.to_string(),
             funder: input_accounts.funder.to_string(),
             whirlpool: input_accounts.whirlpool.to_string(),
             token_vault_a: input_accounts.token_vault_a.to_string(),
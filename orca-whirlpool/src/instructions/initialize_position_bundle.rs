@@ -0,0 +1,45 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{
+    initialize_position_bundle, InitializePositionBundle,
+};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct InitializePositionBundleInstruction {}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct InitializePositionBundleInstructionAccounts<'a> {
+    pub position_bundle: Address<'a>,
+    pub position_bundle_mint: Address<'a>,
+    pub position_bundle_token_account: Address<'a>,
+    pub position_bundle_owner: Address<'a>,
+    pub funder: Address<'a>,
+    pub token_program: Address<'a>,
+    pub system_program: Address<'a>,
+    pub rent: Address<'a>,
+    pub associated_token_program: Address<'a>,
+}
+
+pub fn process_initialize_position_bundle(
+    _data: InitializePositionBundleInstruction,
+    input_accounts: InitializePositionBundleInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::InitializePositionBundle(InitializePositionBundle {
+        accounts: Some(initialize_position_bundle::Accounts {
+            position_bundle: input_accounts.position_bundle.to_string(),
+            position_bundle_mint: input_accounts.position_bundle_mint.to_string(),
+            position_bundle_token_account: input_accounts.position_bundle_token_account.to_string(),
+            position_bundle_owner: input_accounts.position_bundle_owner.to_string(),
+            funder: input_accounts.funder.to_string(),
+            token_program: input_accounts.token_program.to_string(),
+            system_program: input_accounts.system_program.to_string(),
+            rent: input_accounts.rent.to_string(),
+            associated_token_program: input_accounts.associated_token_program.to_string(),
+        }),
+    }))
+}
@@ -0,0 +1,47 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{initialize_reward, InitializeReward};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct InitializeRewardInstruction {
+    // The reward slot of the Whirlpool (0, 1 or 2) to initialize.
+    pub reward_index: u8,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct InitializeRewardInstructionAccounts<'a> {
+    pub reward_authority: Address<'a>,
+    pub funder: Address<'a>,
+    pub whirlpool: Address<'a>,
+    pub reward_mint: Address<'a>,
+    pub reward_vault: Address<'a>,
+    pub token_program: Address<'a>,
+    pub system_program: Address<'a>,
+    pub rent: Address<'a>,
+}
+
+pub fn process_initialize_reward(
+    data: InitializeRewardInstruction,
+    input_accounts: InitializeRewardInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::InitializeReward(InitializeReward {
+        instruction: Some(initialize_reward::Instruction {
+            reward_index: data.reward_index as u32,
+        }),
+        accounts: Some(initialize_reward::Accounts {
+            reward_authority: input_accounts.reward_authority.to_string(),
+            funder: input_accounts.funder.to_string(),
+            whirlpool: input_accounts.whirlpool.to_string(),
+            reward_mint: input_accounts.reward_mint.to_string(),
+            reward_vault: input_accounts.reward_vault.to_string(),
+            token_program: input_accounts.token_program.to_string(),
+            system_program: input_accounts.system_program.to_string(),
+            rent: input_accounts.rent.to_string(),
+        }),
+    }))
+}
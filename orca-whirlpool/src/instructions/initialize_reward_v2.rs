@@ -0,0 +1,49 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{initialize_reward_v2, InitializeRewardV2};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct InitializeRewardInstructionV2 {
+    // The reward slot of the Whirlpool (0, 1 or 2) to initialize.
+    pub reward_index: u8,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct InitializeRewardInstructionAccountsV2<'a> {
+    pub reward_authority: Address<'a>,
+    pub funder: Address<'a>,
+    pub whirlpool: Address<'a>,
+    pub reward_mint: Address<'a>,
+    pub reward_token_badge: Address<'a>,
+    pub reward_vault: Address<'a>,
+    pub reward_token_program: Address<'a>,
+    pub system_program: Address<'a>,
+    pub rent: Address<'a>,
+}
+
+pub fn process_initialize_reward_v2(
+    data: InitializeRewardInstructionV2,
+    input_accounts: InitializeRewardInstructionAccountsV2,
+) -> Option<Type> {
+    Some(Type::InitializeRewardV2(InitializeRewardV2 {
+        instruction: Some(initialize_reward_v2::Instruction {
+            reward_index: data.reward_index as u32,
+        }),
+        accounts: Some(initialize_reward_v2::Accounts {
+            reward_authority: input_accounts.reward_authority.to_string(),
+            funder: input_accounts.funder.to_string(),
+            whirlpool: input_accounts.whirlpool.to_string(),
+            reward_mint: input_accounts.reward_mint.to_string(),
+            reward_token_badge: input_accounts.reward_token_badge.to_string(),
+            reward_vault: input_accounts.reward_vault.to_string(),
+            reward_token_program: input_accounts.reward_token_program.to_string(),
+            system_program: input_accounts.system_program.to_string(),
+            rent: input_accounts.rent.to_string(),
+        }),
+    }))
+}
@@ -1,9 +1,32 @@
+pub mod close_bundled_position;
+pub mod close_position;
+pub mod close_position_with_token_extensions;
+pub mod collect_fees;
+pub mod collect_fees_v2;
+pub mod collect_reward;
+pub mod collect_reward_v2;
 pub mod decrease_liquidity;
 pub mod decrease_liquidity_v2;
 pub mod increase_liquidity;
 pub mod increase_liquidity_v2;
+pub mod initialize_config;
+pub mod initialize_fee_tier;
 pub mod initialize_pool;
 pub mod initialize_pool_v2;
+pub mod initialize_position_bundle;
+pub mod initialize_position_bundle_with_metadata;
+pub mod initialize_reward;
+pub mod initialize_reward_v2;
+pub mod open_bundled_position;
+pub mod open_position;
+pub mod open_position_with_metadata;
+pub mod open_position_with_token_extensions;
+pub mod set_default_fee_rate;
+pub mod set_default_protocol_fee_rate;
+pub mod set_fee_rate;
+pub mod set_protocol_fee_rate;
+pub mod set_reward_emissions;
+pub mod set_reward_emissions_v2;
 pub mod swap;
 pub mod swap_v2;
 pub mod two_hop_swap;
@@ -0,0 +1,53 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{open_bundled_position, OpenBundledPosition};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct OpenBundledPositionInstruction {
+    // The index of the slot in the position bundle used by this position.
+    pub bundle_index: u16,
+    // The tick index of the lower bound of the position.
+    pub tick_lower_index: i32,
+    // The tick index of the upper bound of the position.
+    pub tick_upper_index: i32,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct OpenBundledPositionInstructionAccounts<'a> {
+    pub bundled_position: Address<'a>,
+    pub position_bundle: Address<'a>,
+    pub position_bundle_token_account: Address<'a>,
+    pub position_bundle_authority: Address<'a>,
+    pub whirlpool: Address<'a>,
+    pub funder: Address<'a>,
+    pub system_program: Address<'a>,
+    pub rent: Address<'a>,
+}
+
+pub fn process_open_bundled_position(
+    data: OpenBundledPositionInstruction,
+    input_accounts: OpenBundledPositionInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::OpenBundledPosition(OpenBundledPosition {
+        instruction: Some(open_bundled_position::Instruction {
+            bundle_index: data.bundle_index as u32,
+            tick_lower_index: data.tick_lower_index,
+            tick_upper_index: data.tick_upper_index,
+        }),
+        accounts: Some(open_bundled_position::Accounts {
+            bundled_position: input_accounts.bundled_position.to_string(),
+            position_bundle: input_accounts.position_bundle.to_string(),
+            position_bundle_token_account: input_accounts.position_bundle_token_account.to_string(),
+            position_bundle_authority: input_accounts.position_bundle_authority.to_string(),
+            whirlpool: input_accounts.whirlpool.to_string(),
+            funder: input_accounts.funder.to_string(),
+            system_program: input_accounts.system_program.to_string(),
+            rent: input_accounts.rent.to_string(),
+        }),
+    }))
+}
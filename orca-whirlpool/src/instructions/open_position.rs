@@ -0,0 +1,59 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{open_position, OpenPosition};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+use super::utils::OpenPositionBumps;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct OpenPositionInstruction {
+    // The bump value when deriving the PDA of the Position address.
+    pub bumps: OpenPositionBumps,
+    // The tick index of the lower bound of the position.
+    pub tick_lower_index: i32,
+    // The tick index of the upper bound of the position.
+    pub tick_upper_index: i32,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct OpenPositionInstructionAccounts<'a> {
+    pub funder: Address<'a>,
+    pub owner: Address<'a>,
+    pub position: Address<'a>,
+    pub position_mint: Address<'a>,
+    pub position_token_account: Address<'a>,
+    pub whirlpool: Address<'a>,
+    pub token_program: Address<'a>,
+    pub system_program: Address<'a>,
+    pub rent: Address<'a>,
+    pub associated_token_program: Address<'a>,
+}
+
+pub fn process_open_position(
+    data: OpenPositionInstruction,
+    input_accounts: OpenPositionInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::OpenPosition(OpenPosition {
+        instruction: Some(open_position::Instruction {
+            position_bump: data.bumps.position_bump as u32,
+            tick_lower_index: data.tick_lower_index,
+            tick_upper_index: data.tick_upper_index,
+        }),
+        accounts: Some(open_position::Accounts {
+            funder: input_accounts.funder.to_string(),
+            owner: input_accounts.owner.to_string(),
+            position: input_accounts.position.to_string(),
+            position_mint: input_accounts.position_mint.to_string(),
+            position_token_account: input_accounts.position_token_account.to_string(),
+            whirlpool: input_accounts.whirlpool.to_string(),
+            token_program: input_accounts.token_program.to_string(),
+            system_program: input_accounts.system_program.to_string(),
+            rent: input_accounts.rent.to_string(),
+            associated_token_program: input_accounts.associated_token_program.to_string(),
+        }),
+    }))
+}
@@ -0,0 +1,68 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{
+    open_position_with_metadata, OpenPositionWithMetadata,
+};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+use super::utils::OpenPositionWithMetadataBumps;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct OpenPositionWithMetadataInstruction {
+    // The bump values when deriving the PDAs of the Position and its metadata account.
+    pub bumps: OpenPositionWithMetadataBumps,
+    // The tick index of the lower bound of the position.
+    pub tick_lower_index: i32,
+    // The tick index of the upper bound of the position.
+    pub tick_upper_index: i32,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct OpenPositionWithMetadataInstructionAccounts<'a> {
+    pub funder: Address<'a>,
+    pub owner: Address<'a>,
+    pub position: Address<'a>,
+    pub position_mint: Address<'a>,
+    pub position_metadata_account: Address<'a>,
+    pub position_token_account: Address<'a>,
+    pub whirlpool: Address<'a>,
+    pub token_program: Address<'a>,
+    pub system_program: Address<'a>,
+    pub rent: Address<'a>,
+    pub associated_token_program: Address<'a>,
+    pub metadata_program: Address<'a>,
+    pub metadata_update_auth: Address<'a>,
+}
+
+pub fn process_open_position_with_metadata(
+    data: OpenPositionWithMetadataInstruction,
+    input_accounts: OpenPositionWithMetadataInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::OpenPositionWithMetadata(OpenPositionWithMetadata {
+        instruction: Some(open_position_with_metadata::Instruction {
+            position_bump: data.bumps.position_bump as u32,
+            metadata_bump: data.bumps.metadata_bump as u32,
+            tick_lower_index: data.tick_lower_index,
+            tick_upper_index: data.tick_upper_index,
+        }),
+        accounts: Some(open_position_with_metadata::Accounts {
+            funder: input_accounts.funder.to_string(),
+            owner: input_accounts.owner.to_string(),
+            position: input_accounts.position.to_string(),
+            position_mint: input_accounts.position_mint.to_string(),
+            position_metadata_account: input_accounts.position_metadata_account.to_string(),
+            position_token_account: input_accounts.position_token_account.to_string(),
+            whirlpool: input_accounts.whirlpool.to_string(),
+            token_program: input_accounts.token_program.to_string(),
+            system_program: input_accounts.system_program.to_string(),
+            rent: input_accounts.rent.to_string(),
+            associated_token_program: input_accounts.associated_token_program.to_string(),
+            metadata_program: input_accounts.metadata_program.to_string(),
+            metadata_update_auth: input_accounts.metadata_update_auth.to_string(),
+        }),
+    }))
+}
@@ -0,0 +1,61 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{
+    open_position_with_token_extensions, OpenPositionWithTokenExtensions,
+};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct OpenPositionWithTokenExtensionsInstruction {
+    // The tick index of the lower bound of the position.
+    pub tick_lower_index: i32,
+    // The tick index of the upper bound of the position.
+    pub tick_upper_index: i32,
+    // If true, the position mint is initialized with the token metadata extension.
+    pub with_token_metadata_extension: bool,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct OpenPositionWithTokenExtensionsInstructionAccounts<'a> {
+    pub funder: Address<'a>,
+    pub owner: Address<'a>,
+    pub position: Address<'a>,
+    pub position_mint: Address<'a>,
+    pub position_token_account: Address<'a>,
+    pub whirlpool: Address<'a>,
+    pub token_2022_program: Address<'a>,
+    pub system_program: Address<'a>,
+    pub associated_token_program: Address<'a>,
+    pub metadata_update_auth: Address<'a>,
+}
+
+pub fn process_open_position_with_token_extensions(
+    data: OpenPositionWithTokenExtensionsInstruction,
+    input_accounts: OpenPositionWithTokenExtensionsInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::OpenPositionWithTokenExtensions(
+        OpenPositionWithTokenExtensions {
+            instruction: Some(open_position_with_token_extensions::Instruction {
+                tick_lower_index: data.tick_lower_index,
+                tick_upper_index: data.tick_upper_index,
+                with_token_metadata_extension: data.with_token_metadata_extension,
+            }),
+            accounts: Some(open_position_with_token_extensions::Accounts {
+                funder: input_accounts.funder.to_string(),
+                owner: input_accounts.owner.to_string(),
+                position: input_accounts.position.to_string(),
+                position_mint: input_accounts.position_mint.to_string(),
+                position_token_account: input_accounts.position_token_account.to_string(),
+                whirlpool: input_accounts.whirlpool.to_string(),
+                token_2022_program: input_accounts.token_2022_program.to_string(),
+                system_program: input_accounts.system_program.to_string(),
+                associated_token_program: input_accounts.associated_token_program.to_string(),
+                metadata_update_auth: input_accounts.metadata_update_auth.to_string(),
+            }),
+        },
+    ))
+}
@@ -0,0 +1,37 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{set_default_fee_rate, SetDefaultFeeRate};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct SetDefaultFeeRateInstruction {
+    // The fee rate assigned to new pools of the fee tier, in hundredths of a basis point.
+    pub default_fee_rate: u16,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct SetDefaultFeeRateInstructionAccounts<'a> {
+    pub whirlpools_config: Address<'a>,
+    pub fee_tier: Address<'a>,
+    pub fee_authority: Address<'a>,
+}
+
+pub fn process_set_default_fee_rate(
+    data: SetDefaultFeeRateInstruction,
+    input_accounts: SetDefaultFeeRateInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::SetDefaultFeeRate(SetDefaultFeeRate {
+        instruction: Some(set_default_fee_rate::Instruction {
+            default_fee_rate: data.default_fee_rate as u32,
+        }),
+        accounts: Some(set_default_fee_rate::Accounts {
+            whirlpools_config: input_accounts.whirlpools_config.to_string(),
+            fee_tier: input_accounts.fee_tier.to_string(),
+            fee_authority: input_accounts.fee_authority.to_string(),
+        }),
+    }))
+}
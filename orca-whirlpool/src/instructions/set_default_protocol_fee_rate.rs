@@ -0,0 +1,37 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{
+    set_default_protocol_fee_rate, SetDefaultProtocolFeeRate,
+};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct SetDefaultProtocolFeeRateInstruction {
+    // The protocol fee rate assigned to new pools, in basis points of the fee.
+    pub default_protocol_fee_rate: u16,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct SetDefaultProtocolFeeRateInstructionAccounts<'a> {
+    pub whirlpools_config: Address<'a>,
+    pub fee_authority: Address<'a>,
+}
+
+pub fn process_set_default_protocol_fee_rate(
+    data: SetDefaultProtocolFeeRateInstruction,
+    input_accounts: SetDefaultProtocolFeeRateInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::SetDefaultProtocolFeeRate(SetDefaultProtocolFeeRate {
+        instruction: Some(set_default_protocol_fee_rate::Instruction {
+            default_protocol_fee_rate: data.default_protocol_fee_rate as u32,
+        }),
+        accounts: Some(set_default_protocol_fee_rate::Accounts {
+            whirlpools_config: input_accounts.whirlpools_config.to_string(),
+            fee_authority: input_accounts.fee_authority.to_string(),
+        }),
+    }))
+}
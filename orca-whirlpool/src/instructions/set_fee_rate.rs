@@ -0,0 +1,37 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{set_fee_rate, SetFeeRate};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct SetFeeRateInstruction {
+    // The new fee rate of the Whirlpool, in hundredths of a basis point.
+    pub fee_rate: u16,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct SetFeeRateInstructionAccounts<'a> {
+    pub whirlpools_config: Address<'a>,
+    pub whirlpool: Address<'a>,
+    pub fee_authority: Address<'a>,
+}
+
+pub fn process_set_fee_rate(
+    data: SetFeeRateInstruction,
+    input_accounts: SetFeeRateInstructionAccounts,
+) -> Option<Type> {
+    Some(Type::SetFeeRate(SetFeeRate {
+        instruction: Some(set_fee_rate::Instruction {
+            fee_rate: data.fee_rate as u32,
+        }),
+        accounts: Some(set_fee_rate::Accounts {
+            whirlpools_config: input_accounts.whirlpools_config.to_string(),
+            whirlpool: input_accounts.whirlpool.to_string(),
+            fee_authority: input_accounts.fee_authority.to_string(),
+        }),
+    }))
+}
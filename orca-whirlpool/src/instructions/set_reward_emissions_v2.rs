@@ -0,0 +1,40 @@
+use crate::pb::messari::orca_whirlpool::v1::event::Type;
+use crate::pb::messari::orca_whirlpool::v1::{set_reward_emissions_v2, SetRewardEmissionsV2};
+use crate::traits::account_deserialize::AccountsDeserialize;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use derive_deserialize::AccountsDeserialize;
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::Address;
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct SetRewardEmissionsInstructionV2 {
+    // The reward slot of the Whirlpool (0, 1 or 2) to update.
+    pub reward_index: u8,
+    // The amount of reward tokens emitted per second, as a Q64.64 number.
+    pub emissions_per_second_x64: u128,
+}
+
+#[derive(AccountsDeserialize, Debug)]
+pub struct SetRewardEmissionsInstructionAccountsV2<'a> {
+    pub whirlpool: Address<'a>,
+    pub reward_authority: Address<'a>,
+    pub reward_vault: Address<'a>,
+}
+
+pub fn process_set_reward_emissions_v2(
+    data: SetRewardEmissionsInstructionV2,
+    input_accounts: SetRewardEmissionsInstructionAccountsV2,
+) -> Option<Type> {
+    Some(Type::SetRewardEmissionsV2(SetRewardEmissionsV2 {
+        instruction: Some(set_reward_emissions_v2::Instruction {
+            reward_index: data.reward_index as u32,
+            emissions_per_second_x64: data.emissions_per_second_x64.to_string(),
+        }),
+        accounts: Some(set_reward_emissions_v2::Accounts {
+            whirlpool: input_accounts.whirlpool.to_string(),
+            reward_authority: input_accounts.reward_authority.to_string(),
+            reward_vault: input_accounts.reward_vault.to_string(),
+        }),
+    }))
+}
@@ -1,13 +1,12 @@
 use crate::pb::messari::orca_whirlpool::v1::event::Type;
 use crate::pb::messari::orca_whirlpool::v1::{orca_swap_v2, OrcaSwapV2};
 use crate::traits::account_deserialize::AccountsDeserialize;
-use crate::traits::balance_of::BalanceOf;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
 use crate::utils;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use derive_deserialize::AccountsDeserialize;
 use substreams_solana::block_view::InstructionView;
-use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::Address;
 
 use super::utils::RemainingAccountsInfo;
@@ -50,12 +49,12 @@ pub struct SwapInstructionAccountsV2<'a> {
 pub fn process_swap_v2(
     data: SwapInstructionV2,
     input_accounts: SwapInstructionAccountsV2,
-    confirmed_txn: &ConfirmedTransaction,
+    balances: &InstructionBalances,
 ) -> Option<Type> {
     let (token_a_pre_bal, token_a_post_bal) =
-        confirmed_txn.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_a);
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_a);
     let (token_b_pre_bal, token_b_post_bal) =
-        confirmed_txn.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_b);
+        balances.balance_of(&input_accounts.whirlpool, &input_accounts.token_vault_b);
 
     Some(Type::SwapV2(OrcaSwapV2 {
         instruction: Some(orca_swap_v2::Instruction {
@@ -69,6 +68,9 @@ pub fn process_swap_v2(
             amount_b_pre: token_b_pre_bal.clone(),
             amount_b_post: token_b_post_bal.clone(),
 
+            transfer_fee_a: balances.transfer_fee_of(&input_accounts.token_vault_a),
+            transfer_fee_b: balances.transfer_fee_of(&input_accounts.token_vault_b),
+
             other_amount_threshold: data.other_amount_threshold.to_string(),
             sqrt_price_limit: data.sqrt_price_limit.to_string(),
 
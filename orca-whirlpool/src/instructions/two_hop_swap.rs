@@ -1,13 +1,12 @@
 use crate::pb::messari::orca_whirlpool::v1::event::Type;
 use crate::pb::messari::orca_whirlpool::v1::{two_hop_swap, TwoHopSwap};
 use crate::traits::account_deserialize::AccountsDeserialize;
-use crate::traits::balance_of::BalanceOf;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
 use crate::utils;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use derive_deserialize::AccountsDeserialize;
 use substreams_solana::block_view::InstructionView;
-use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::Address;
 
 #[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
@@ -55,21 +54,21 @@ pub struct TwoHopSwapInstructionAccounts<'a> {
 pub fn process_two_hop_swap(
     data: TwoHopSwapInstruction,
     input_accounts: TwoHopSwapInstructionAccounts,
-    confirmed_txn: &ConfirmedTransaction,
+    balances: &InstructionBalances,
 ) -> Option<Type> {
-    let (token_a_one_pre_bal, token_a_one_post_bal) = confirmed_txn.balance_of(
+    let (token_a_one_pre_bal, token_a_one_post_bal) = balances.balance_of(
         &input_accounts.whirlpool_one,
         &input_accounts.token_vault_one_a,
     );
-    let (token_b_one_pre_bal, token_b_one_post_bal) = confirmed_txn.balance_of(
+    let (token_b_one_pre_bal, token_b_one_post_bal) = balances.balance_of(
         &input_accounts.whirlpool_one,
         &input_accounts.token_vault_one_b,
     );
-    let (token_a_two_pre_bal, token_a_two_post_bal) = confirmed_txn.balance_of(
+    let (token_a_two_pre_bal, token_a_two_post_bal) = balances.balance_of(
         &input_accounts.whirlpool_two,
         &input_accounts.token_vault_two_a,
     );
-    let (token_b_two_pre_bal, token_b_two_post_bal) = confirmed_txn.balance_of(
+    let (token_b_two_pre_bal, token_b_two_post_bal) = balances.balance_of(
         &input_accounts.whirlpool_two,
         &input_accounts.token_vault_two_b,
     );
@@ -1,13 +1,12 @@
 use crate::pb::messari::orca_whirlpool::v1::event::Type;
 use crate::pb::messari::orca_whirlpool::v1::{two_hop_swap_v2, TwoHopSwapV2};
 use crate::traits::account_deserialize::AccountsDeserialize;
-use crate::traits::balance_of::BalanceOf;
+use crate::traits::balance_of::{BalanceOf, InstructionBalances};
 use crate::utils;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use derive_deserialize::AccountsDeserialize;
 use substreams_solana::block_view::InstructionView;
-use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::Address;
 
 use super::utils::RemainingAccountsInfo;
@@ -63,21 +62,21 @@ pub struct TwoHopSwapInstructionAccountsV2<'a> {
 pub fn process_two_hop_swap_v2(
     data: TwoHopSwapInstructionV2,
     input_accounts: TwoHopSwapInstructionAccountsV2,
-    confirmed_txn: &ConfirmedTransaction,
+    balances: &InstructionBalances,
 ) -> Option<Type> {
-    let (token_a_one_pre_bal, token_a_one_post_bal) = confirmed_txn.balance_of(
+    let (token_a_one_pre_bal, token_a_one_post_bal) = balances.balance_of(
         &input_accounts.whirlpool_one,
         &input_accounts.token_vault_one_input,
     );
-    let (token_b_one_pre_bal, token_b_one_post_bal) = confirmed_txn.balance_of(
+    let (token_b_one_pre_bal, token_b_one_post_bal) = balances.balance_of(
         &input_accounts.whirlpool_one,
         &input_accounts.token_vault_one_intermediate,
     );
-    let (token_a_two_pre_bal, token_a_two_post_bal) = confirmed_txn.balance_of(
+    let (token_a_two_pre_bal, token_a_two_post_bal) = balances.balance_of(
         &input_accounts.whirlpool_two,
         &input_accounts.token_vault_two_intermediate,
     );
-    let (token_b_two_pre_bal, token_b_two_post_bal) = confirmed_txn.balance_of(
+    let (token_b_two_pre_bal, token_b_two_post_bal) = balances.balance_of(
         &input_accounts.whirlpool_two,
         &input_accounts.token_vault_two_output,
     );
@@ -116,6 +115,13 @@ pub fn process_two_hop_swap_v2(
             amount_b_two_pre: token_b_two_pre_bal.clone(),
             amount_b_two_post: token_b_two_post_bal.clone(),
 
+            transfer_fee_a_one: balances.transfer_fee_of(&input_accounts.token_vault_one_input),
+            transfer_fee_b_one: balances
+                .transfer_fee_of(&input_accounts.token_vault_one_intermediate),
+            transfer_fee_a_two: balances
+                .transfer_fee_of(&input_accounts.token_vault_two_intermediate),
+            transfer_fee_b_two: balances.transfer_fee_of(&input_accounts.token_vault_two_output),
+
             other_amount_threshold: data.other_amount_threshold.to_string(),
 
             amount_specified_is_input: data.amount_specified_is_input,
@@ -18,6 +18,17 @@ pub struct WhirlpoolBumps {
     pub whirlpool_bump: u8,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct OpenPositionBumps {
+    pub position_bump: u8,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, PartialEq, Debug)]
+pub struct OpenPositionWithMetadataBumps {
+    pub position_bump: u8,
+    pub metadata_bump: u8,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
 pub struct RemainingAccountsSlice {
     pub accounts_type: AccountsType,
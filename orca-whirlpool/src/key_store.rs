@@ -7,6 +7,7 @@ pub enum StoreKey {
     User,
     Deposit,
     Withdraw,
+    Position,
     PoolBalance,
     PoolLiquidity,
     TotalPoolCount,
@@ -14,11 +15,72 @@ pub enum StoreKey {
     TxnCount,
     SwapCount,
     ActiveUsers,
+    LiquidityProvider,
+    Depositor,
+    Withdrawer,
+    Trader,
+    CumulativeUniqueLPs,
+    CumulativeUniqueDepositors,
+    CumulativeUniqueWithdrawers,
+    CumulativeUniqueTraders,
+    ActiveDepositors,
+    ActiveWithdrawers,
+    ActiveTraders,
     DepositCount,
     WithdrawCount,
+    VolumeByTokenAmount,
     DailyVolumeByTokenAmount,
+    CollectedFees,
+    DailyCollectedFees,
+    RewardToken,
+    CollectedRewards,
+    PositionCollectedRewards,
+    FeeTierFeeRate,
+    ConfigProtocolFeeRate,
+    PoolFeeRate,
+    PoolProtocolFeeRate,
+    TotalRevenue,
+    SupplySideRevenue,
+    ProtocolSideRevenue,
+    DailyTotalRevenue,
+    DailySupplySideRevenue,
+    DailyProtocolSideRevenue,
+    WhirlpoolsConfig,
+    FeeTier,
+    PoolPrice,
+    Token,
+    TokenPrice,
+    TokenPools,
+    VolumeUSD,
+    DailyVolumeUSD,
+    HourlyVolumeByTokenAmount,
+    HourlyVolumeUSD,
+    PoolSnapshotDay,
+    PoolSnapshotBlock,
+    LastRolledDay,
+    LastRolledHour,
+    PositionLiquidity,
+    PositionDepositCount,
+    PositionWithdrawCount,
+    PositionDepositedAmount,
+    PositionWithdrawnAmount,
+    PositionMint,
+    PositionBundleMint,
+    BundlePosition,
+    PositionOwner,
+    AccountSwapCount,
+    AccountDepositCount,
+    AccountWithdrawCount,
+    AccountVolumeByTokenAmount,
+    ProtocolVolumeByTokenAmount,
+    PoolUser,
+    PoolTotalValueLocked,
+    ProtocolTotalValueLocked,
     PoolDailySnapshot(i64, Option<Box<StoreKey>>),
+    PoolHourlySnapshot(i64, Option<Box<StoreKey>>),
     UsageMetricsDailySnapshot(i64, Option<Box<StoreKey>>),
+    UsageMetricsHourlySnapshot(i64, Option<Box<StoreKey>>),
+    FinancialsDailySnapshot(i64, Option<Box<StoreKey>>),
 }
 
 impl StoreKey {
@@ -40,15 +102,98 @@ impl StoreKey {
                 let field_id = field.as_ref().map(|f| f.unique_id()).unwrap_or_default();
                 format!("UsageMetricsDailySnapshot:{day_id}:{field_id}")
             }
+            StoreKey::UsageMetricsHourlySnapshot(hour_id, field) => {
+                let field_id = field.as_ref().map(|f| f.unique_id()).unwrap_or_default();
+                format!("UsageMetricsHourlySnapshot:{hour_id}:{field_id}")
+            }
             StoreKey::PoolDailySnapshot(day_id, field) => {
                 let field_id = field.as_ref().map(|f| f.unique_id()).unwrap_or_default();
                 format!("PoolDailySnapshot:{day_id}:{field_id}")
             }
+            StoreKey::PoolHourlySnapshot(hour_id, field) => {
+                let field_id = field.as_ref().map(|f| f.unique_id()).unwrap_or_default();
+                format!("PoolHourlySnapshot:{hour_id}:{field_id}")
+            }
+            StoreKey::FinancialsDailySnapshot(day_id, field) => {
+                let field_id = field.as_ref().map(|f| f.unique_id()).unwrap_or_default();
+                format!("FinancialsDailySnapshot:{day_id}:{field_id}")
+            }
             _ => format!("{}", self),
         }
     }
 }
 
+/// Length of the periods that protocol and pool timeseries are bucketed in.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Granularity {
+    Daily,
+    Hourly,
+}
+
+impl Granularity {
+    pub const ALL: [Granularity; 2] = [Granularity::Daily, Granularity::Hourly];
+
+    /// ID of the period `seconds` falls in: the number of days or hours since Unix epoch.
+    pub fn period_id(&self, seconds: i64) -> i64 {
+        match self {
+            Granularity::Daily => seconds / 86400,
+            Granularity::Hourly => seconds / 3600,
+        }
+    }
+
+    /// Prefix of the entity names and fields of this granularity, e.g. `Daily` and `dailySwapCount`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Granularity::Daily => "Daily",
+            Granularity::Hourly => "Hourly",
+        }
+    }
+
+    pub fn usage_metrics_snapshot(&self, period_id: i64, field: Option<StoreKey>) -> StoreKey {
+        let field = field.map(Box::new);
+        match self {
+            Granularity::Daily => StoreKey::UsageMetricsDailySnapshot(period_id, field),
+            Granularity::Hourly => StoreKey::UsageMetricsHourlySnapshot(period_id, field),
+        }
+    }
+
+    pub fn pool_snapshot(&self, period_id: i64, field: Option<StoreKey>) -> StoreKey {
+        let field = field.map(Box::new);
+        match self {
+            Granularity::Daily => StoreKey::PoolDailySnapshot(period_id, field),
+            Granularity::Hourly => StoreKey::PoolHourlySnapshot(period_id, field),
+        }
+    }
+
+    /// `snapshot` if this is the daily granularity, for the stores that only keep daily
+    /// snapshots and evict nothing when an hour is rolled over.
+    pub fn daily_snapshot(&self, snapshot: StoreKey) -> Option<StoreKey> {
+        (*self == Granularity::Daily).then_some(snapshot)
+    }
+
+    /// Key of the last period rolled over to, in `store_rolled_periods`.
+    pub fn last_rolled_period(&self) -> StoreKey {
+        match self {
+            Granularity::Daily => StoreKey::LastRolledDay,
+            Granularity::Hourly => StoreKey::LastRolledHour,
+        }
+    }
+
+    pub fn volume_by_token_amount(&self) -> StoreKey {
+        match self {
+            Granularity::Daily => StoreKey::DailyVolumeByTokenAmount,
+            Granularity::Hourly => StoreKey::HourlyVolumeByTokenAmount,
+        }
+    }
+
+    pub fn volume_usd(&self) -> StoreKey {
+        match self {
+            Granularity::Daily => StoreKey::DailyVolumeUSD,
+            Granularity::Hourly => StoreKey::HourlyVolumeUSD,
+        }
+    }
+}
+
 impl fmt::Display for StoreKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -57,6 +202,7 @@ impl fmt::Display for StoreKey {
             StoreKey::User => "USER",
             StoreKey::Deposit => "DEPOSIT",
             StoreKey::Withdraw => "WITHDRAW",
+            StoreKey::Position => "POSITION",
             StoreKey::PoolBalance => "POOL_BALANCE",
             StoreKey::PoolLiquidity => "POOL_LIQUIDITY",
             StoreKey::TotalPoolCount => "TOTAL_POOL_COUNT",
@@ -64,11 +210,72 @@ impl fmt::Display for StoreKey {
             StoreKey::TxnCount => "TXN_COUNT",
             StoreKey::SwapCount => "SWAP_COUNT",
             StoreKey::ActiveUsers => "ACTIVE_USERS",
+            StoreKey::LiquidityProvider => "LIQUIDITY_PROVIDER",
+            StoreKey::Depositor => "DEPOSITOR",
+            StoreKey::Withdrawer => "WITHDRAWER",
+            StoreKey::Trader => "TRADER",
+            StoreKey::CumulativeUniqueLPs => "CUMULATIVE_UNIQUE_LPS",
+            StoreKey::CumulativeUniqueDepositors => "CUMULATIVE_UNIQUE_DEPOSITORS",
+            StoreKey::CumulativeUniqueWithdrawers => "CUMULATIVE_UNIQUE_WITHDRAWERS",
+            StoreKey::CumulativeUniqueTraders => "CUMULATIVE_UNIQUE_TRADERS",
+            StoreKey::ActiveDepositors => "ACTIVE_DEPOSITORS",
+            StoreKey::ActiveWithdrawers => "ACTIVE_WITHDRAWERS",
+            StoreKey::ActiveTraders => "ACTIVE_TRADERS",
             StoreKey::DepositCount => "DEPOSIT_COUNT",
             StoreKey::WithdrawCount => "WITHDRAW_COUNT",
+            StoreKey::VolumeByTokenAmount => "VOLUME_BY_TOKEN_AMOUNT",
             StoreKey::DailyVolumeByTokenAmount => "DAILY_VOLUME_BY_TOKEN_AMOUNT",
+            StoreKey::CollectedFees => "COLLECTED_FEES",
+            StoreKey::DailyCollectedFees => "DAILY_COLLECTED_FEES",
+            StoreKey::RewardToken => "REWARD_TOKEN",
+            StoreKey::CollectedRewards => "COLLECTED_REWARDS",
+            StoreKey::PositionCollectedRewards => "POSITION_COLLECTED_REWARDS",
+            StoreKey::FeeTierFeeRate => "FEE_TIER_FEE_RATE",
+            StoreKey::ConfigProtocolFeeRate => "CONFIG_PROTOCOL_FEE_RATE",
+            StoreKey::PoolFeeRate => "POOL_FEE_RATE",
+            StoreKey::PoolProtocolFeeRate => "POOL_PROTOCOL_FEE_RATE",
+            StoreKey::TotalRevenue => "TOTAL_REVENUE",
+            StoreKey::SupplySideRevenue => "SUPPLY_SIDE_REVENUE",
+            StoreKey::ProtocolSideRevenue => "PROTOCOL_SIDE_REVENUE",
+            StoreKey::DailyTotalRevenue => "DAILY_TOTAL_REVENUE",
+            StoreKey::DailySupplySideRevenue => "DAILY_SUPPLY_SIDE_REVENUE",
+            StoreKey::DailyProtocolSideRevenue => "DAILY_PROTOCOL_SIDE_REVENUE",
+            StoreKey::WhirlpoolsConfig => "WHIRLPOOLS_CONFIG",
+            StoreKey::FeeTier => "FEE_TIER",
+            StoreKey::PoolPrice => "POOL_PRICE",
+            StoreKey::Token => "TOKEN",
+            StoreKey::TokenPrice => "TOKEN_PRICE",
+            StoreKey::TokenPools => "TOKEN_POOLS",
+            StoreKey::VolumeUSD => "VOLUME_USD",
+            StoreKey::DailyVolumeUSD => "DAILY_VOLUME_USD",
+            StoreKey::HourlyVolumeByTokenAmount => "HOURLY_VOLUME_BY_TOKEN_AMOUNT",
+            StoreKey::HourlyVolumeUSD => "HOURLY_VOLUME_USD",
+            StoreKey::PoolSnapshotDay => "POOL_SNAPSHOT_DAY",
+            StoreKey::PoolSnapshotBlock => "POOL_SNAPSHOT_BLOCK",
+            StoreKey::LastRolledDay => "LAST_ROLLED_DAY",
+            StoreKey::LastRolledHour => "LAST_ROLLED_HOUR",
+            StoreKey::PositionLiquidity => "POSITION_LIQUIDITY",
+            StoreKey::PositionDepositCount => "POSITION_DEPOSIT_COUNT",
+            StoreKey::PositionWithdrawCount => "POSITION_WITHDRAW_COUNT",
+            StoreKey::PositionDepositedAmount => "POSITION_DEPOSITED_AMOUNT",
+            StoreKey::PositionWithdrawnAmount => "POSITION_WITHDRAWN_AMOUNT",
+            StoreKey::PositionMint => "POSITION_MINT",
+            StoreKey::PositionBundleMint => "POSITION_BUNDLE_MINT",
+            StoreKey::BundlePosition => "BUNDLE_POSITION",
+            StoreKey::PositionOwner => "POSITION_OWNER",
+            StoreKey::AccountSwapCount => "ACCOUNT_SWAP_COUNT",
+            StoreKey::AccountDepositCount => "ACCOUNT_DEPOSIT_COUNT",
+            StoreKey::AccountWithdrawCount => "ACCOUNT_WITHDRAW_COUNT",
+            StoreKey::AccountVolumeByTokenAmount => "ACCOUNT_VOLUME_BY_TOKEN_AMOUNT",
+            StoreKey::ProtocolVolumeByTokenAmount => "PROTOCOL_VOLUME_BY_TOKEN_AMOUNT",
+            StoreKey::PoolUser => "POOL_USER",
+            StoreKey::PoolTotalValueLocked => "POOL_TOTAL_VALUE_LOCKED",
+            StoreKey::ProtocolTotalValueLocked => "PROTOCOL_TOTAL_VALUE_LOCKED",
             StoreKey::UsageMetricsDailySnapshot(_, _) => "USAGE_METRICS_DAILY_SNAPSHOT",
+            StoreKey::UsageMetricsHourlySnapshot(_, _) => "USAGE_METRICS_HOURLY_SNAPSHOT",
             StoreKey::PoolDailySnapshot(_, _) => "POOL_DAILY_SNAPSHOT",
+            StoreKey::PoolHourlySnapshot(_, _) => "POOL_HOURLY_SNAPSHOT",
+            StoreKey::FinancialsDailySnapshot(_, _) => "FINANCIALS_DAILY_SNAPSHOT",
         };
         write!(f, "{}", s)
     }
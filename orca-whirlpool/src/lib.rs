@@ -3,6 +3,7 @@ mod db;
 mod instructions;
 mod key_store;
 mod modules;
+mod orca_events;
 mod orca_instructions;
 mod pb;
 mod traits;
@@ -1,12 +1,17 @@
-use crate::pb::messari::orca_whirlpool::v1::{Pool, Pools, Swaps, Deposits, Withdraws};
+use crate::pb::messari::orca_whirlpool::v1::{
+    Configs, Deposits, FeeCollections, Pool, PoolPrice, Pools, Position, Positions, RewardToken,
+    Rewards, Swaps, Token, Withdraws,
+};
 
+use crate::key_store::Granularity;
 use crate::{constants, db};
 
 use substreams::pb::substreams::Clock;
 use substreams::scalar::BigInt;
 use substreams::skip_empty_output;
 use substreams::store::{
-    DeltaBigInt, DeltaInt64, Deltas, StoreGet, StoreGetBigInt, StoreGetInt64, StoreGetProto,
+    DeltaBigDecimal, DeltaBigInt, DeltaInt64, DeltaProto, DeltaString, Deltas, StoreGet,
+    StoreGetBigDecimal, StoreGetBigInt, StoreGetInt64, StoreGetProto,
 };
 use substreams_entity_change::pb::entity::EntityChanges;
 use substreams_entity_change::tables::Tables;
@@ -16,6 +21,7 @@ fn graph_out(
     clock: Clock,
     initialized_pools: Pools,
     pools_store: StoreGetProto<Pool>,
+    unique_users_deltas: Deltas<DeltaBigInt>,
     active_users_store: StoreGetInt64,
     cumulative_users_delta: Deltas<DeltaInt64>,
     total_pool_count_store: StoreGetInt64,
@@ -25,15 +31,52 @@ fn graph_out(
     pool_liquidity_store: StoreGetBigInt,
     pool_liquidity_delta: Deltas<DeltaBigInt>,
     user_activity_deltas: Deltas<DeltaBigInt>,
+    volume_by_token_amount_store: StoreGetBigInt,
     volume_by_token_amount_deltas: Deltas<DeltaBigInt>,
+    collected_fees_store: StoreGetBigInt,
+    collected_fees_deltas: Deltas<DeltaBigInt>,
+    pool_fee_rates_deltas: Deltas<DeltaInt64>,
+    swap_fees_store: StoreGetBigInt,
+    swap_fees_deltas: Deltas<DeltaBigInt>,
+    pool_prices_deltas: Deltas<DeltaProto<PoolPrice>>,
+    tokens_deltas: Deltas<DeltaProto<Token>>,
+    token_prices_store: StoreGetBigDecimal,
+    token_prices_deltas: Deltas<DeltaBigDecimal>,
+    volume_usd_store: StoreGetBigDecimal,
+    volume_usd_deltas: Deltas<DeltaBigDecimal>,
+    pool_tvl_store: StoreGetBigDecimal,
+    pool_tvl_deltas: Deltas<DeltaBigDecimal>,
+    protocol_tvl_store: StoreGetBigDecimal,
+    protocol_tvl_deltas: Deltas<DeltaBigDecimal>,
+    financials_store: StoreGetBigDecimal,
+    financials_deltas: Deltas<DeltaBigDecimal>,
+    financials_by_token_store: StoreGetBigInt,
+    financials_by_token_deltas: Deltas<DeltaBigInt>,
+    pool_activity_store: StoreGetBigInt,
+    pool_activity_deltas: Deltas<DeltaBigInt>,
+    pool_snapshot_days_deltas: Deltas<DeltaInt64>,
     map_deposits: Deposits,
     map_withdraws: Withdraws,
     map_swaps: Swaps,
+    map_positions: Positions,
+    positions_store: StoreGetProto<Position>,
+    position_liquidity_store: StoreGetBigInt,
+    position_liquidity_deltas: Deltas<DeltaBigInt>,
+    position_owners_deltas: Deltas<DeltaString>,
+    account_activity_deltas: Deltas<DeltaBigInt>,
+    map_fee_collections: FeeCollections,
+    map_rewards: Rewards,
+    reward_tokens_store: StoreGetProto<RewardToken>,
+    collected_rewards_deltas: Deltas<DeltaBigInt>,
+    map_configs: Configs,
+    configs_store: StoreGetInt64,
 ) -> Result<EntityChanges, ()> {
     skip_empty_output();
 
     let block_number = BigInt::from(clock.number);
-    let timestamp = BigInt::from(clock.timestamp.unwrap().seconds);
+    let seconds = clock.timestamp.unwrap().seconds;
+    let timestamp = BigInt::from(seconds);
+    let day_id = Granularity::Daily.period_id(seconds);
 
     let mut tables = Tables::new();
     let is_initialized = clock.number != 124280237;
@@ -43,23 +86,77 @@ fn graph_out(
         &mut tables,
         cumulative_users_delta,
         total_pool_count_delta,
+        &protocol_tvl_deltas,
+        &user_activity_deltas,
         &protocol_id,
         is_initialized,
     );
 
+    db::handle_financials_daily_snapshot_entity(
+        &mut tables,
+        &db::FinancialsStores {
+            financials: &financials_store,
+            by_token: &financials_by_token_store,
+            protocol_tvl: &protocol_tvl_store,
+        },
+        &db::FinancialsDeltas {
+            financials: &financials_deltas,
+            by_token: &financials_by_token_deltas,
+            protocol_tvl: &protocol_tvl_deltas,
+        },
+        &protocol_id,
+        day_id,
+        &block_number,
+        &timestamp,
+    );
+
+    let pool_stores = db::PoolStores {
+        pools: &pools_store,
+        balances: &pool_balances_store,
+        liquidity: &pool_liquidity_store,
+        volume_by_token_amount: &volume_by_token_amount_store,
+        collected_fees: &collected_fees_store,
+        swap_fees: &swap_fees_store,
+        volume_usd: &volume_usd_store,
+        tvl: &pool_tvl_store,
+        activity: &pool_activity_store,
+    };
+    let pool_deltas = db::PoolDeltas {
+        balances: &pool_balances_delta,
+        liquidity: &pool_liquidity_delta,
+        volume_by_token_amount: &volume_by_token_amount_deltas,
+        collected_fees: &collected_fees_deltas,
+        fee_rates: &pool_fee_rates_deltas,
+        swap_fees: &swap_fees_deltas,
+        prices: &pool_prices_deltas,
+        volume_usd: &volume_usd_deltas,
+        tvl: &pool_tvl_deltas,
+        activity: &pool_activity_deltas,
+        snapshot_days: &pool_snapshot_days_deltas,
+    };
+
     db::handle_pool_entity(
         &mut tables,
         initialized_pools,
-        &pools_store,
-        &pool_balances_delta,
-        &pool_liquidity_delta,
+        &pool_stores,
+        &pool_deltas,
         &protocol_id,
     );
 
-    db::handle_usage_metrics_daily_snapshot_entity(
+    db::handle_token_entity(
+        &mut tables,
+        &tokens_deltas,
+        &token_prices_deltas,
+        &map_deposits,
+        &map_withdraws,
+        &map_swaps,
+        &block_number,
+    );
+
+    db::handle_usage_metrics_snapshot_entity(
         &mut tables,
-        active_users_store,
-        total_pool_count_store,
+        &active_users_store,
+        &total_pool_count_store,
         &user_activity_deltas,
         &protocol_id,
         &block_number,
@@ -68,18 +165,78 @@ fn graph_out(
 
     db::handle_liquidity_pool_daily_snapshot_entity(
         &mut tables,
-        &pools_store,
-        &pool_balances_store,
-        &pool_liquidity_store,
-        &volume_by_token_amount_deltas,
+        &pool_stores,
+        &pool_deltas,
         &protocol_id,
+        day_id,
         &block_number,
         &timestamp,
     );
 
-    db::handle_deposit_entity(&mut tables, map_deposits, &protocol_id);
-    db::handle_withdraw_entity(&mut tables, map_withdraws, &protocol_id);
-    db::handle_swap_entity(&mut tables, map_swaps, &protocol_id);
+    db::handle_liquidity_pool_hourly_snapshot_entity(
+        &mut tables,
+        &pool_stores,
+        &pool_deltas,
+        &protocol_id,
+        Granularity::Hourly.period_id(seconds),
+        &block_number,
+        &timestamp,
+    );
+
+    db::handle_deposit_entity(
+        &mut tables,
+        map_deposits,
+        &pools_store,
+        &positions_store,
+        &token_prices_store,
+        &protocol_id,
+    );
+    db::handle_withdraw_entity(
+        &mut tables,
+        map_withdraws,
+        &pools_store,
+        &positions_store,
+        &token_prices_store,
+        &protocol_id,
+    );
+    db::handle_swap_entity(
+        &mut tables,
+        map_swaps,
+        &pools_store,
+        &token_prices_store,
+        &protocol_id,
+    );
+    db::handle_position_entity(
+        &mut tables,
+        &map_positions,
+        &db::PositionStores {
+            pools: &pools_store,
+            positions: &positions_store,
+            liquidity: &position_liquidity_store,
+        },
+        &db::PositionDeltas {
+            liquidity: &position_liquidity_deltas,
+            owners: &position_owners_deltas,
+        },
+        &protocol_id,
+        &block_number,
+        &timestamp,
+    );
+    db::handle_account_entity(
+        &mut tables,
+        &unique_users_deltas,
+        &account_activity_deltas,
+        &timestamp,
+    );
+    db::handle_fee_collection_entity(&mut tables, map_fee_collections, &protocol_id);
+    db::handle_reward_entity(
+        &mut tables,
+        &map_rewards,
+        &reward_tokens_store,
+        &collected_rewards_deltas,
+        &protocol_id,
+    );
+    db::handle_config_entity(&mut tables, &map_configs, &configs_store, &protocol_id);
 
     Ok(tables.to_entity_changes())
 }
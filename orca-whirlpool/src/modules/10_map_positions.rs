@@ -0,0 +1,224 @@
+use crate::{
+    key_store::StoreKey,
+    pb::messari::orca_whirlpool::v1::{
+        event::Type, Event, Events, Pool, Position, PositionBundle, Positions,
+    },
+};
+use substreams::{
+    log, skip_empty_output,
+    store::{StoreGet, StoreGetProto},
+};
+
+#[substreams::handlers::map]
+pub fn map_positions(
+    raw_events: Events,
+    pools_store: StoreGetProto<Pool>,
+) -> Result<Positions, substreams::errors::Error> {
+    skip_empty_output();
+
+    let mut positions: Vec<Position> = Vec::new();
+    let mut bundles: Vec<PositionBundle> = Vec::new();
+
+    for event in raw_events.data {
+        if let Some(event_type) = event.r#type.clone() {
+            match event_type {
+                Type::OpenPosition(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    process_open_position(
+                        open_position(
+                            accounts.position,
+                            accounts.whirlpool,
+                            accounts.position_mint,
+                            accounts.owner,
+                            instruction.tick_lower_index,
+                            instruction.tick_upper_index,
+                        ),
+                        &pools_store,
+                        &event,
+                        &mut positions,
+                    );
+                }
+                Type::OpenPositionWithMetadata(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    process_open_position(
+                        open_position(
+                            accounts.position,
+                            accounts.whirlpool,
+                            accounts.position_mint,
+                            accounts.owner,
+                            instruction.tick_lower_index,
+                            instruction.tick_upper_index,
+                        ),
+                        &pools_store,
+                        &event,
+                        &mut positions,
+                    );
+                }
+                Type::OpenPositionWithTokenExtensions(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    process_open_position(
+                        open_position(
+                            accounts.position,
+                            accounts.whirlpool,
+                            accounts.position_mint,
+                            accounts.owner,
+                            instruction.tick_lower_index,
+                            instruction.tick_upper_index,
+                        ),
+                        &pools_store,
+                        &event,
+                        &mut positions,
+                    );
+                }
+                Type::OpenBundledPosition(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    process_open_position(
+                        Position {
+                            address: accounts.bundled_position,
+                            whirlpool: accounts.whirlpool,
+                            owner: accounts.position_bundle_authority,
+                            tick_lower_index: instruction.tick_lower_index,
+                            tick_upper_index: instruction.tick_upper_index,
+                            position_bundle: Some(accounts.position_bundle),
+                            bundle_index: Some(instruction.bundle_index),
+                            ..Default::default()
+                        },
+                        &pools_store,
+                        &event,
+                        &mut positions,
+                    );
+                }
+                Type::ClosePosition(e) => {
+                    let accounts = e.accounts.unwrap();
+                    positions.push(with_event_metadata(
+                        Position {
+                            address: accounts.position,
+                            position_mint: accounts.position_mint,
+                            owner: accounts.position_authority,
+                            is_closed: true,
+                            ..Default::default()
+                        },
+                        &event,
+                    ));
+                }
+                Type::ClosePositionWithTokenExtensions(e) => {
+                    let accounts = e.accounts.unwrap();
+                    positions.push(with_event_metadata(
+                        Position {
+                            address: accounts.position,
+                            position_mint: accounts.position_mint,
+                            owner: accounts.position_authority,
+                            is_closed: true,
+                            ..Default::default()
+                        },
+                        &event,
+                    ));
+                }
+                Type::CloseBundledPosition(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    positions.push(with_event_metadata(
+                        Position {
+                            address: accounts.bundled_position,
+                            owner: accounts.position_bundle_authority,
+                            position_bundle: Some(accounts.position_bundle),
+                            bundle_index: Some(instruction.bundle_index),
+                            is_closed: true,
+                            ..Default::default()
+                        },
+                        &event,
+                    ));
+                }
+                Type::InitializePositionBundle(e) => {
+                    let accounts = e.accounts.unwrap();
+                    bundles.push(process_initialize_position_bundle(
+                        accounts.position_bundle,
+                        accounts.position_bundle_mint,
+                        accounts.position_bundle_owner,
+                        &event,
+                    ));
+                }
+                Type::InitializePositionBundleWithMetadata(e) => {
+                    let accounts = e.accounts.unwrap();
+                    bundles.push(process_initialize_position_bundle(
+                        accounts.position_bundle,
+                        accounts.position_bundle_mint,
+                        accounts.position_bundle_owner,
+                        &event,
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(Positions {
+        data: positions,
+        bundles,
+    })
+}
+
+fn open_position(
+    address: String,
+    whirlpool: String,
+    position_mint: String,
+    owner: String,
+    tick_lower_index: i32,
+    tick_upper_index: i32,
+) -> Position {
+    Position {
+        address,
+        whirlpool,
+        position_mint,
+        owner,
+        tick_lower_index,
+        tick_upper_index,
+        ..Default::default()
+    }
+}
+
+fn process_open_position(
+    position: Position,
+    pool_store: &StoreGetProto<Pool>,
+    event: &Event,
+    positions: &mut Vec<Position>,
+) {
+    if pool_store
+        .get_last(StoreKey::Pool.get_unique_key(&position.whirlpool))
+        .is_none()
+    {
+        log::info!("Pool not found: {:?}", position.whirlpool);
+        return;
+    }
+
+    positions.push(with_event_metadata(position, event));
+}
+
+fn with_event_metadata(position: Position, event: &Event) -> Position {
+    Position {
+        slot: event.slot,
+        txn_id: event.txn_id.clone(),
+        block_height: event.block_height,
+        block_timestamp: event.block_timestamp,
+        block_hash: event.block_hash.clone(),
+        ..position
+    }
+}
+
+fn process_initialize_position_bundle(
+    address: String,
+    position_bundle_mint: String,
+    owner: String,
+    event: &Event,
+) -> PositionBundle {
+    PositionBundle {
+        address,
+        position_bundle_mint,
+        owner,
+
+        slot: event.slot,
+        txn_id: event.txn_id.clone(),
+        block_height: event.block_height,
+        block_timestamp: event.block_timestamp,
+        block_hash: event.block_hash.clone(),
+    }
+}
@@ -0,0 +1,20 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSet, StoreSetProto};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{Position, Positions};
+
+#[substreams::handlers::store]
+pub fn store_positions(positions: Positions, store: StoreSetProto<Position>) {
+    skip_empty_output();
+
+    // Bundled position accounts are re-created when a bundle slot is reused,
+    // so the latest open always wins.
+    for position in positions.data.iter().filter(|p| !p.is_closed) {
+        store.set(
+            0,
+            StoreKey::Position.get_unique_key(&position.address),
+            position,
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    key_store::StoreKey,
+    pb::messari::orca_whirlpool::v1::{
+        event::Type, Event, Events, FeeCollection, FeeCollections, Pool,
+    },
+    traits::fee_collection_instructions::FeeCollectionInstruction,
+    utils,
+};
+use substreams::{
+    log,
+    scalar::BigInt,
+    skip_empty_output,
+    store::{StoreGet, StoreGetProto},
+};
+
+#[substreams::handlers::map]
+pub fn map_fee_collections(
+    raw_events: Events,
+    pools_store: StoreGetProto<Pool>,
+) -> Result<FeeCollections, substreams::errors::Error> {
+    skip_empty_output();
+    let mut fee_collections: Vec<FeeCollection> = Vec::new();
+
+    for event in raw_events.data {
+        if let Some(event_type) = event.r#type.clone() {
+            match event_type {
+                Type::CollectFees(instruction) => {
+                    process_fee_collection(
+                        &instruction,
+                        &pools_store,
+                        &event,
+                        &mut fee_collections,
+                    );
+                }
+                Type::CollectFeesV2(instruction) => {
+                    process_fee_collection(
+                        &instruction,
+                        &pools_store,
+                        &event,
+                        &mut fee_collections,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(FeeCollections {
+        data: fee_collections,
+    })
+}
+
+fn process_fee_collection<T: FeeCollectionInstruction>(
+    fee_collection_event: &T,
+    pool_store: &StoreGetProto<Pool>,
+    event: &Event,
+    fee_collections: &mut Vec<FeeCollection>,
+) {
+    let pool = match pool_store
+        .get_last(StoreKey::Pool.get_unique_key(&fee_collection_event.whirlpool()))
+    {
+        Some(pool) => pool,
+        None => {
+            log::info!("Pool not found: {:?}", fee_collection_event.whirlpool());
+            return;
+        }
+    };
+
+    // Fees leave the pool vaults, so the vault balance difference is negative.
+    let collected_amount = |amount: String| {
+        BigInt::try_from(amount)
+            .unwrap_or_default()
+            .absolute()
+            .to_string()
+    };
+
+    fee_collections.push(FeeCollection {
+        id: utils::instruction_id("COLLECT_FEES", event, 0),
+
+        pool: fee_collection_event.whirlpool(),
+        position: fee_collection_event.position(),
+
+        token_a: pool.token_mint_a,
+        token_b: pool.token_mint_b,
+
+        token_a_balance: fee_collection_event.amount_a_post(),
+        token_b_balance: fee_collection_event.amount_b_post(),
+
+        amount_a: collected_amount(fee_collection_event.amount_a()),
+        amount_b: collected_amount(fee_collection_event.amount_b()),
+
+        from: fee_collection_event.whirlpool(),
+        to: fee_collection_event.position_authority(),
+
+        slot: event.slot,
+        txn_id: event.txn_id.clone(),
+        block_height: event.block_height,
+        block_timestamp: event.block_timestamp,
+        block_hash: event.block_hash.clone(),
+    });
+}
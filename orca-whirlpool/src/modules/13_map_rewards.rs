@@ -0,0 +1,222 @@
+use crate::{
+    key_store::StoreKey,
+    pb::messari::orca_whirlpool::v1::{
+        event::Type, Event, Events, Pool, RewardCollection, RewardEmissions, RewardToken, Rewards,
+    },
+    utils,
+};
+use substreams::{
+    log,
+    scalar::BigInt,
+    skip_empty_output,
+    store::{StoreGet, StoreGetProto},
+};
+
+#[substreams::handlers::map]
+pub fn map_rewards(
+    raw_events: Events,
+    pools_store: StoreGetProto<Pool>,
+) -> Result<Rewards, substreams::errors::Error> {
+    skip_empty_output();
+
+    let mut rewards = Rewards::default();
+
+    for event in raw_events.data {
+        if let Some(event_type) = event.r#type.clone() {
+            match event_type {
+                Type::InitializeReward(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    rewards.tokens.push(process_initialize_reward(
+                        accounts.whirlpool,
+                        instruction.reward_index,
+                        accounts.reward_mint,
+                        accounts.reward_vault,
+                        accounts.reward_authority,
+                        accounts.token_program,
+                        &event,
+                    ));
+                }
+                Type::InitializeRewardV2(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    rewards.tokens.push(process_initialize_reward(
+                        accounts.whirlpool,
+                        instruction.reward_index,
+                        accounts.reward_mint,
+                        accounts.reward_vault,
+                        accounts.reward_authority,
+                        accounts.reward_token_program,
+                        &event,
+                    ));
+                }
+                Type::SetRewardEmissions(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    rewards.emissions.push(process_set_reward_emissions(
+                        accounts.whirlpool,
+                        instruction.reward_index,
+                        instruction.emissions_per_second_x64,
+                        accounts.reward_authority,
+                        &event,
+                    ));
+                }
+                Type::SetRewardEmissionsV2(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    rewards.emissions.push(process_set_reward_emissions(
+                        accounts.whirlpool,
+                        instruction.reward_index,
+                        instruction.emissions_per_second_x64,
+                        accounts.reward_authority,
+                        &event,
+                    ));
+                }
+                Type::CollectReward(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    rewards.collections.push(process_collect_reward(
+                        accounts.whirlpool,
+                        instruction.reward_index,
+                        accounts.position,
+                        accounts.position_authority,
+                        instruction.amount,
+                        instruction.amount_post,
+                        &event,
+                    ));
+                }
+                Type::CollectRewardV2(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    rewards.collections.push(process_collect_reward(
+                        accounts.whirlpool,
+                        instruction.reward_index,
+                        accounts.position,
+                        accounts.position_authority,
+                        instruction.amount,
+                        instruction.amount_post,
+                        &event,
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Only rewards of pools that are indexed are kept.
+    rewards
+        .tokens
+        .retain(|token| is_indexed_pool(&pools_store, &token.pool));
+    rewards
+        .emissions
+        .retain(|emissions| is_indexed_pool(&pools_store, &emissions.pool));
+    rewards
+        .collections
+        .retain(|collection| is_indexed_pool(&pools_store, &collection.pool));
+
+    Ok(rewards)
+}
+
+fn is_indexed_pool(pool_store: &StoreGetProto<Pool>, pool: &str) -> bool {
+    if pool_store
+        .get_last(StoreKey::Pool.get_unique_key(pool))
+        .is_none()
+    {
+        log::info!("Pool not found: {:?}", pool);
+        return false;
+    }
+
+    true
+}
+
+/// Returns the ID of the reward token occupying `reward_index` in `pool`.
+pub fn reward_token_id(pool: &str, reward_index: u32) -> String {
+    format!("{pool}-{reward_index}")
+}
+
+fn process_initialize_reward(
+    pool: String,
+    reward_index: u32,
+    mint: String,
+    vault: String,
+    authority: String,
+    token_program: String,
+    event: &Event,
+) -> RewardToken {
+    RewardToken {
+        id: reward_token_id(&pool, reward_index),
+
+        pool,
+        reward_index,
+
+        mint,
+        vault,
+        authority,
+        token_program,
+
+        slot: event.slot,
+        txn_id: event.txn_id.clone(),
+        block_height: event.block_height,
+        block_timestamp: event.block_timestamp,
+        block_hash: event.block_hash.clone(),
+    }
+}
+
+fn process_set_reward_emissions(
+    pool: String,
+    reward_index: u32,
+    emissions_per_second_x64: String,
+    authority: String,
+    event: &Event,
+) -> RewardEmissions {
+    let reward_token = reward_token_id(&pool, reward_index);
+
+    RewardEmissions {
+        id: utils::instruction_id("REWARD_EMISSIONS", event, 0),
+
+        reward_token,
+        pool,
+        reward_index,
+
+        emissions_per_second_x64,
+        authority,
+
+        slot: event.slot,
+        txn_id: event.txn_id.clone(),
+        block_height: event.block_height,
+        block_timestamp: event.block_timestamp,
+        block_hash: event.block_hash.clone(),
+    }
+}
+
+fn process_collect_reward(
+    pool: String,
+    reward_index: u32,
+    position: String,
+    position_authority: String,
+    amount: Option<String>,
+    vault_balance: Option<String>,
+    event: &Event,
+) -> RewardCollection {
+    let reward_token = reward_token_id(&pool, reward_index);
+
+    // Rewards leave the reward vault, so the vault balance difference is negative.
+    let amount = BigInt::try_from(amount.unwrap_or_default())
+        .unwrap_or_default()
+        .absolute();
+
+    RewardCollection {
+        id: utils::instruction_id("COLLECT_REWARD", event, 0),
+
+        reward_token,
+        pool: pool.clone(),
+        reward_index,
+        position,
+
+        amount: amount.to_string(),
+        vault_balance: vault_balance.unwrap_or_default(),
+
+        from: pool,
+        to: position_authority,
+
+        slot: event.slot,
+        txn_id: event.txn_id.clone(),
+        block_height: event.block_height,
+        block_timestamp: event.block_timestamp,
+        block_hash: event.block_hash.clone(),
+    }
+}
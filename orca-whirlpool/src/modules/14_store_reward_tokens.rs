@@ -0,0 +1,18 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsProto};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{RewardToken, Rewards};
+
+#[substreams::handlers::store]
+pub fn store_reward_tokens(rewards: Rewards, store: StoreSetIfNotExistsProto<RewardToken>) {
+    skip_empty_output();
+
+    for reward_token in rewards.tokens {
+        store.set_if_not_exists(
+            0,
+            StoreKey::RewardToken.get_unique_key(&reward_token.id),
+            &reward_token,
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSet, StoreSetInt64};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{event::Type, Events};
+
+#[substreams::handlers::store]
+pub fn store_fee_tiers(raw_events: Events, store: StoreSetInt64) {
+    skip_empty_output();
+
+    for event in raw_events.data {
+        match event.r#type {
+            Some(Type::InitializeConfig(e)) => {
+                let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                store.set(
+                    0,
+                    StoreKey::ConfigProtocolFeeRate.get_unique_key(&accounts.config),
+                    &(instruction.default_protocol_fee_rate as i64),
+                );
+            }
+            Some(Type::SetDefaultProtocolFeeRate(e)) => {
+                let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                store.set(
+                    0,
+                    StoreKey::ConfigProtocolFeeRate.get_unique_key(&accounts.whirlpools_config),
+                    &(instruction.default_protocol_fee_rate as i64),
+                );
+            }
+            Some(Type::InitializeFeeTier(e)) => {
+                let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                store.set(
+                    0,
+                    StoreKey::FeeTierFeeRate.get_unique_key(&accounts.fee_tier),
+                    &(instruction.default_fee_rate as i64),
+                );
+            }
+            Some(Type::SetDefaultFeeRate(e)) => {
+                let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                store.set(
+                    0,
+                    StoreKey::FeeTierFeeRate.get_unique_key(&accounts.fee_tier),
+                    &(instruction.default_fee_rate as i64),
+                );
+            }
+            _ => {}
+        }
+    }
+}
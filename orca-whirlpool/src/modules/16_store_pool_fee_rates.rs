@@ -0,0 +1,84 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreGet, StoreGetInt64, StoreNew, StoreSet, StoreSetInt64};
+
+use crate::constants::DEFAULT_PROTOCOL_FEE_RATE;
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{event::Type, Events};
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_pool_fee_rates(
+    raw_events: Events,
+    fee_tiers_store: StoreGetInt64,
+    store: StoreSetInt64,
+) {
+    skip_empty_output();
+
+    for event in raw_events.data {
+        match event.r#type {
+            Some(Type::InitializePool(e)) => {
+                let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                set_initial_fee_rates(
+                    &accounts.whirlpool,
+                    &accounts.whirlpools_config,
+                    &accounts.fee_tier,
+                    instruction.tick_spacing,
+                    &fee_tiers_store,
+                    &store,
+                );
+            }
+            Some(Type::InitializePoolV2(e)) => {
+                let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                set_initial_fee_rates(
+                    &accounts.whirlpool,
+                    &accounts.whirlpools_config,
+                    &accounts.fee_tier,
+                    instruction.tick_spacing,
+                    &fee_tiers_store,
+                    &store,
+                );
+            }
+            Some(Type::SetFeeRate(e)) => {
+                let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                store.set(
+                    0,
+                    StoreKey::PoolFeeRate.get_unique_key(&accounts.whirlpool),
+                    &(instruction.fee_rate as i64),
+                );
+            }
+            Some(Type::SetProtocolFeeRate(e)) => {
+                let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                store.set(
+                    0,
+                    StoreKey::PoolProtocolFeeRate.get_unique_key(&accounts.whirlpool),
+                    &(instruction.protocol_fee_rate as i64),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn set_initial_fee_rates(
+    pool: &str,
+    config: &str,
+    fee_tier: &str,
+    tick_spacing: u32,
+    fee_tiers_store: &StoreGetInt64,
+    store: &StoreSetInt64,
+) {
+    // New pools inherit the default rates of their fee tier and config.
+    let fee_rate = fee_tiers_store
+        .get_last(StoreKey::FeeTierFeeRate.get_unique_key(fee_tier))
+        .unwrap_or_else(|| utils::default_fee_rate(tick_spacing));
+    let protocol_fee_rate = fee_tiers_store
+        .get_last(StoreKey::ConfigProtocolFeeRate.get_unique_key(config))
+        .unwrap_or(DEFAULT_PROTOCOL_FEE_RATE);
+
+    store.set(0, StoreKey::PoolFeeRate.get_unique_key(pool), &fee_rate);
+    store.set(
+        0,
+        StoreKey::PoolProtocolFeeRate.get_unique_key(pool),
+        &protocol_fee_rate,
+    );
+}
@@ -0,0 +1,164 @@
+use crate::{
+    key_store::StoreKey,
+    pb::messari::orca_whirlpool::v1::{
+        event::Type, Configs, Event, Events, FeeRateChange, FeeTier, Pool, WhirlpoolsConfig,
+    },
+    utils,
+};
+use substreams::{
+    log, skip_empty_output,
+    store::{StoreGet, StoreGetProto},
+};
+
+#[substreams::handlers::map]
+pub fn map_configs(
+    raw_events: Events,
+    pools_store: StoreGetProto<Pool>,
+) -> Result<Configs, substreams::errors::Error> {
+    skip_empty_output();
+
+    let mut configs = Configs::default();
+
+    for event in raw_events.data {
+        if let Some(event_type) = event.r#type.clone() {
+            match event_type {
+                Type::InitializeConfig(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    configs.configs.push(WhirlpoolsConfig {
+                        address: accounts.config,
+
+                        fee_authority: instruction.fee_authority,
+                        collect_protocol_fees_authority: instruction
+                            .collect_protocol_fees_authority,
+                        reward_emissions_super_authority: instruction
+                            .reward_emissions_super_authority,
+
+                        default_protocol_fee_rate: instruction.default_protocol_fee_rate,
+
+                        slot: event.slot,
+                        txn_id: event.txn_id.clone(),
+                        block_height: event.block_height,
+                        block_timestamp: event.block_timestamp,
+                        block_hash: event.block_hash.clone(),
+                    });
+                }
+                Type::InitializeFeeTier(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    configs.fee_tiers.push(FeeTier {
+                        address: accounts.fee_tier,
+                        whirlpools_config: accounts.config,
+
+                        tick_spacing: instruction.tick_spacing,
+                        default_fee_rate: instruction.default_fee_rate,
+
+                        slot: event.slot,
+                        txn_id: event.txn_id.clone(),
+                        block_height: event.block_height,
+                        block_timestamp: event.block_timestamp,
+                        block_hash: event.block_hash.clone(),
+                    });
+                }
+                Type::SetDefaultFeeRate(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    configs.fee_rate_changes.push(fee_rate_change(
+                        "DEFAULT_FEE_RATE",
+                        accounts.whirlpools_config,
+                        Some(accounts.fee_tier),
+                        None,
+                        instruction.default_fee_rate,
+                        accounts.fee_authority,
+                        &event,
+                    ));
+                }
+                Type::SetDefaultProtocolFeeRate(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    configs.fee_rate_changes.push(fee_rate_change(
+                        "DEFAULT_PROTOCOL_FEE_RATE",
+                        accounts.whirlpools_config,
+                        None,
+                        None,
+                        instruction.default_protocol_fee_rate,
+                        accounts.fee_authority,
+                        &event,
+                    ));
+                }
+                Type::SetFeeRate(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    if !pool_exists(&pools_store, &accounts.whirlpool) {
+                        continue;
+                    }
+
+                    configs.fee_rate_changes.push(fee_rate_change(
+                        "FEE_RATE",
+                        accounts.whirlpools_config,
+                        None,
+                        Some(accounts.whirlpool),
+                        instruction.fee_rate,
+                        accounts.fee_authority,
+                        &event,
+                    ));
+                }
+                Type::SetProtocolFeeRate(e) => {
+                    let (instruction, accounts) = (e.instruction.unwrap(), e.accounts.unwrap());
+                    if !pool_exists(&pools_store, &accounts.whirlpool) {
+                        continue;
+                    }
+
+                    configs.fee_rate_changes.push(fee_rate_change(
+                        "PROTOCOL_FEE_RATE",
+                        accounts.whirlpools_config,
+                        None,
+                        Some(accounts.whirlpool),
+                        instruction.protocol_fee_rate,
+                        accounts.fee_authority,
+                        &event,
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(configs)
+}
+
+fn pool_exists(pools_store: &StoreGetProto<Pool>, pool: &str) -> bool {
+    if pools_store
+        .get_last(StoreKey::Pool.get_unique_key(pool))
+        .is_none()
+    {
+        log::info!("Pool not found: {:?}", pool);
+        return false;
+    }
+
+    true
+}
+
+fn fee_rate_change(
+    kind: &str,
+    whirlpools_config: String,
+    fee_tier: Option<String>,
+    pool: Option<String>,
+    rate: u32,
+    authority: String,
+    event: &Event,
+) -> FeeRateChange {
+    FeeRateChange {
+        id: utils::instruction_id(kind, event, 0),
+
+        kind: kind.to_string(),
+
+        whirlpools_config,
+        fee_tier,
+        pool,
+
+        rate,
+        authority,
+
+        slot: event.slot,
+        txn_id: event.txn_id.clone(),
+        block_height: event.block_height,
+        block_timestamp: event.block_timestamp,
+        block_hash: event.block_hash.clone(),
+    }
+}
@@ -0,0 +1,28 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsInt64};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::Configs;
+
+#[substreams::handlers::store]
+pub fn store_configs(configs: Configs, store: StoreSetIfNotExistsInt64) {
+    skip_empty_output();
+
+    // Only configs and fee tiers initialized after the start block have an entity,
+    // so their creation block is kept to know which ones can be updated.
+    for config in configs.configs {
+        store.set_if_not_exists(
+            0,
+            StoreKey::WhirlpoolsConfig.get_unique_key(&config.address),
+            &(config.block_height as i64),
+        );
+    }
+
+    for fee_tier in configs.fee_tiers {
+        store.set_if_not_exists(
+            0,
+            StoreKey::FeeTier.get_unique_key(&fee_tier.address),
+            &(fee_tier.block_height as i64),
+        );
+    }
+}
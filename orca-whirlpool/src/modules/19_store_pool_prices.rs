@@ -0,0 +1,27 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSet, StoreSetProto};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{PoolPrice, Pools, Swaps};
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_pool_prices(pools: Pools, swaps: Swaps, store: StoreSetProto<PoolPrice>) {
+    skip_empty_output();
+
+    for pool in pools.data {
+        // Without the decimals of both mints the price can't be adjusted, so leave it unset.
+        if let (Some(decimals_a), Some(decimals_b)) = (pool.decimals_a, pool.decimals_b) {
+            let price = utils::pool_price(&pool.initial_sqrt_price, decimals_a, decimals_b, false);
+
+            store.set(0, StoreKey::PoolPrice.get_unique_key(&pool.address), &price);
+        }
+    }
+
+    // Swaps are in block order, so the last one of each pool leaves its latest price.
+    for swap in swaps.data {
+        if let Some(price) = swap.price {
+            store.set(0, StoreKey::PoolPrice.get_unique_key(&swap.to), &price);
+        }
+    }
+}
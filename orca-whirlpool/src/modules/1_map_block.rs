@@ -1,17 +1,45 @@
 use crate::constants;
 use crate::instructions::{
+    close_bundled_position::process_close_bundled_position, close_position::process_close_position,
+    close_position_with_token_extensions::process_close_position_with_token_extensions,
+    collect_fees::process_collect_fees, collect_fees_v2::process_collect_fees_v2,
+    collect_reward::process_collect_reward, collect_reward_v2::process_collect_reward_v2,
     decrease_liquidity::process_decrease_liquidity,
     decrease_liquidity_v2::process_decrease_liquidity_v2,
     increase_liquidity::process_increase_liquidity,
-    increase_liquidity_v2::process_increase_liquidity_v2, initialize_pool::process_initialize_pool,
-    initialize_pool_v2::process_initialize_pool_v2, swap::process_swap, swap_v2::process_swap_v2,
-    two_hop_swap::process_two_hop_swap, two_hop_swap_v2::process_two_hop_swap_v2,
+    increase_liquidity_v2::process_increase_liquidity_v2,
+    initialize_config::process_initialize_config, initialize_fee_tier::process_initialize_fee_tier,
+    initialize_pool::process_initialize_pool, initialize_pool_v2::process_initialize_pool_v2,
+    initialize_position_bundle::process_initialize_position_bundle,
+    initialize_position_bundle_with_metadata::process_initialize_position_bundle_with_metadata,
+    initialize_reward::process_initialize_reward,
+    initialize_reward_v2::process_initialize_reward_v2,
+    open_bundled_position::process_open_bundled_position, open_position::process_open_position,
+    open_position_with_metadata::process_open_position_with_metadata,
+    open_position_with_token_extensions::process_open_position_with_token_extensions,
+    set_default_fee_rate::process_set_default_fee_rate,
+    set_default_protocol_fee_rate::process_set_default_protocol_fee_rate,
+    set_fee_rate::process_set_fee_rate, set_protocol_fee_rate::process_set_protocol_fee_rate,
+    set_reward_emissions::process_set_reward_emissions,
+    set_reward_emissions_v2::process_set_reward_emissions_v2, swap::process_swap,
+    swap_v2::process_swap_v2, two_hop_swap::process_two_hop_swap,
+    two_hop_swap_v2::process_two_hop_swap_v2,
 };
+use crate::orca_events::OrcaEvents;
 use crate::orca_instructions::OrcaInstructions;
-use crate::pb::messari::orca_whirlpool::v1::{Event, Events};
+use crate::pb::messari::orca_whirlpool::v1::{
+    event::Type, AnchorEvent, Event, Events, NftTransfer,
+};
+use crate::traits::balance_of::InstructionBalances;
+use crate::utils::{self, TokenTransfer};
+
+use std::collections::{BTreeSet, HashMap};
+use std::ops::Range;
 
+use substreams::scalar::BigInt;
 use substreams::skip_empty_output;
-use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction};
+use substreams_solana::block_view::InstructionView;
+use substreams_solana::pb::sf::solana::r#type::v1::{Block, ConfirmedTransaction, TokenBalance};
 
 #[substreams::handlers::map]
 fn map_block(block: Block) -> Result<Events, substreams::errors::Error> {
@@ -26,60 +54,403 @@ fn map_block(block: Block) -> Result<Events, substreams::errors::Error> {
 }
 
 fn process_txn(confirmed_txn: &ConfirmedTransaction, block: &Block) -> Vec<Event> {
-    confirmed_txn
+    // Inner instructions are numbered from 1 so that 0 identifies the top-level one.
+    let mut top_level_count: u32 = 0;
+    let mut inner_instruction_index: u32 = 0;
+    let instructions: Vec<(u32, u32, InstructionView)> = confirmed_txn
         .walk_instructions()
-        .filter(|instr| instr.program_id() == constants::ORCA_WHIRLPOOL)
-        .filter_map(|instr| {
-            OrcaInstructions::from(&instr)
-                .and_then(|decoded_instr| process_instruction(decoded_instr, confirmed_txn, block))
+        .map(|instr| {
+            if instr.is_root() {
+                top_level_count += 1;
+                inner_instruction_index = 0;
+            } else {
+                inner_instruction_index += 1;
+            }
+
+            (top_level_count - 1, inner_instruction_index, instr)
+        })
+        .collect();
+
+    let mut transfers: Vec<Option<TokenTransfer>> = instructions
+        .iter()
+        .map(|(_, _, instr)| utils::parse_token_transfer(instr))
+        .collect();
+    withhold_transfer_fees(confirmed_txn, &mut transfers);
+
+    let nft_transfers = process_nft_transfers(confirmed_txn, &instructions, &transfers, block);
+
+    instructions
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, _, instr))| {
+            instr.program_id() == constants::ORCA_WHIRLPOOL && !OrcaEvents::is_event(instr)
         })
+        .filter_map(
+            |(position, (instruction_index, inner_instruction_index, instr))| {
+                let owned = position + 1..owned_end(&instructions, position);
+
+                let balances =
+                    instruction_balances(confirmed_txn, &transfers, position, owned.clone());
+                let anchor_events: Vec<AnchorEvent> = instructions[owned]
+                    .iter()
+                    .filter_map(|(_, _, instr)| OrcaEvents::from(instr))
+                    .map(AnchorEvent::from)
+                    .collect();
+
+                OrcaInstructions::from(instr).and_then(|decoded_instr| {
+                    process_instruction(
+                        decoded_instr,
+                        confirmed_txn,
+                        &balances,
+                        anchor_events,
+                        block,
+                        *instruction_index,
+                        *inner_instruction_index,
+                    )
+                })
+            },
+        )
+        .chain(nft_transfers)
         .collect()
 }
 
+/// Transfers of a single unit of a zero-decimal token. Position NFTs are among them, but
+/// which mints are positions is only known downstream, from `store_position_mints`.
+fn process_nft_transfers(
+    confirmed_txn: &ConfirmedTransaction,
+    instructions: &[(u32, u32, InstructionView)],
+    transfers: &[Option<TokenTransfer>],
+    block: &Block,
+) -> Vec<Event> {
+    if !transfers
+        .iter()
+        .flatten()
+        .any(|transfer| transfer.amount == 1)
+    {
+        return Vec::new();
+    }
+
+    let meta = match confirmed_txn.meta.as_ref() {
+        Some(meta) => meta,
+        None => return Vec::new(),
+    };
+    let accounts: Vec<String> = confirmed_txn
+        .resolved_accounts()
+        .iter()
+        .map(|account| bs58::encode(account).into_string())
+        .collect();
+    let token_balance = |balances: &[TokenBalance], account: &str| {
+        balances
+            .iter()
+            .find(|balance| {
+                accounts
+                    .get(balance.account_index as usize)
+                    .is_some_and(|address| address == account)
+            })
+            .cloned()
+    };
+
+    instructions
+        .iter()
+        .zip(transfers)
+        .filter_map(
+            |((instruction_index, inner_instruction_index, _), transfer)| {
+                let transfer = transfer.as_ref().filter(|transfer| transfer.amount == 1)?;
+
+                let source = token_balance(&meta.pre_token_balances, &transfer.source)?;
+                let destination = token_balance(&meta.post_token_balances, &transfer.destination)?;
+                if source.ui_token_amount.as_ref()?.decimals != 0 {
+                    return None;
+                }
+
+                Some(event(
+                    Type::NftTransfer(NftTransfer {
+                        mint: source.mint,
+                        source: transfer.source.clone(),
+                        destination: transfer.destination.clone(),
+                        source_owner: source.owner,
+                        destination_owner: destination.owner,
+                    }),
+                    confirmed_txn,
+                    Vec::new(),
+                    block,
+                    *instruction_index,
+                    *inner_instruction_index,
+                ))
+            },
+        )
+        .collect()
+}
+
+/// Fills in the Token-2022 transfer fees of the transfers that didn't pass them explicitly,
+/// from the difference between what the transfers credited each account and how much its
+/// balance actually grew over the transaction. The fees are withheld from the transfers
+/// into the account in proportion to their amounts, as fee rates are.
+fn withhold_transfer_fees(
+    confirmed_txn: &ConfirmedTransaction,
+    transfers: &mut [Option<TokenTransfer>],
+) {
+    let meta = match confirmed_txn.meta.as_ref() {
+        Some(meta) => meta,
+        None => return,
+    };
+    let accounts: Vec<String> = confirmed_txn
+        .resolved_accounts()
+        .iter()
+        .map(|account| bs58::encode(account).into_string())
+        .collect();
+    let balance = |balances: &[TokenBalance], account: &str| -> i128 {
+        balances
+            .iter()
+            .find(|balance| {
+                accounts
+                    .get(balance.account_index as usize)
+                    .is_some_and(|address| address == account)
+            })
+            .and_then(|balance| balance.ui_token_amount.as_ref())
+            .and_then(|ui_token_amount| ui_token_amount.amount.parse().ok())
+            .unwrap_or_default()
+    };
+
+    let destinations: BTreeSet<String> = transfers
+        .iter()
+        .flatten()
+        .filter(|transfer| transfer.token_2022 && transfer.fee == 0)
+        .map(|transfer| transfer.destination.clone())
+        .collect();
+
+    for destination in destinations {
+        let credited: i128 = transfers
+            .iter()
+            .flatten()
+            .map(|transfer| {
+                let mut change = 0;
+                if transfer.destination == destination {
+                    change += (transfer.amount - transfer.fee) as i128;
+                }
+                if transfer.source == destination {
+                    change -= transfer.amount as i128;
+                }
+                change
+            })
+            .sum();
+        let actual = balance(&meta.post_token_balances, &destination)
+            - balance(&meta.pre_token_balances, &destination);
+
+        let inbound: Vec<&mut TokenTransfer> = transfers
+            .iter_mut()
+            .flatten()
+            .filter(|transfer| {
+                transfer.token_2022 && transfer.fee == 0 && transfer.destination == destination
+            })
+            .collect();
+        let total: u128 = inbound.iter().map(|transfer| transfer.amount as u128).sum();
+        let withheld = (credited - actual).clamp(0, total as i128) as u128;
+        if withheld == 0 {
+            continue;
+        }
+
+        let mut remaining = withheld;
+        let last = inbound.len() - 1;
+        for (index, transfer) in inbound.into_iter().enumerate() {
+            let fee = if index == last {
+                remaining
+            } else {
+                withheld * transfer.amount as u128 / total
+            };
+            transfer.fee = fee.min(remaining).min(transfer.amount as u128) as u64;
+            remaining -= transfer.fee as u128;
+        }
+    }
+}
+
+/// Position just past the instructions owned by the Whirlpool instruction at `position`:
+/// the CPIs it made, that follow it up to the next instruction at the same or a lower stack
+/// height, the next Whirlpool instruction or the end of its top-level instruction. Event
+/// self-CPIs belong to the instruction that emitted them.
+fn owned_end(instructions: &[(u32, u32, InstructionView)], position: usize) -> usize {
+    let (instruction_index, _, whirlpool_instr) = &instructions[position];
+    let stack_height = whirlpool_instr.stack_height();
+
+    instructions
+        .iter()
+        .enumerate()
+        .skip(position + 1)
+        .find(|(_, (index, _, instr))| {
+            index != instruction_index
+                // Transactions that predate stack heights report them as zero.
+                || (instr.stack_height() > 0 && instr.stack_height() <= stack_height)
+                || (instr.program_id() == constants::ORCA_WHIRLPOOL && !OrcaEvents::is_event(instr))
+        })
+        .map(|(end, _)| end)
+        .unwrap_or(instructions.len())
+}
+
+/// Attributes to the Whirlpool instruction at `position` the token transfers among the
+/// instructions it owns.
+fn instruction_balances<'a>(
+    confirmed_txn: &'a ConfirmedTransaction,
+    transfers: &[Option<TokenTransfer>],
+    position: usize,
+    owned: Range<usize>,
+) -> InstructionBalances<'a> {
+    let own_transfers = transfers[owned].iter().flatten().cloned().collect();
+
+    let mut prior_changes: HashMap<String, BigInt> = HashMap::new();
+    transfers[..position].iter().flatten().for_each(|transfer| {
+        for account in [&transfer.source, &transfer.destination] {
+            let change = prior_changes.entry(account.clone()).or_default();
+            *change = change.clone() + transfer.change_of(account);
+        }
+    });
+
+    InstructionBalances {
+        confirmed_txn,
+        transfers: own_transfers,
+        prior_changes,
+    }
+}
+
 fn process_instruction(
     decoded_instr: OrcaInstructions,
     confirmed_txn: &ConfirmedTransaction,
+    balances: &InstructionBalances,
+    anchor_events: Vec<AnchorEvent>,
     block: &Block,
+    instruction_index: u32,
+    inner_instruction_index: u32,
 ) -> Option<Event> {
     let instr_type = match decoded_instr {
         OrcaInstructions::InitializePool(data, input_accounts) => {
-            process_initialize_pool(data, input_accounts)
+            process_initialize_pool(data, input_accounts, balances)
         }
         OrcaInstructions::InitializePoolV2(data, input_accounts) => {
-            process_initialize_pool_v2(data, input_accounts)
+            process_initialize_pool_v2(data, input_accounts, balances)
         }
         OrcaInstructions::IncreaseLiquidity(data, input_accounts) => {
-            process_increase_liquidity(data, input_accounts, confirmed_txn)
+            process_increase_liquidity(data, input_accounts, balances)
         }
         OrcaInstructions::IncreaseLiquidityV2(data, input_accounts) => {
-            process_increase_liquidity_v2(data, input_accounts, confirmed_txn)
+            process_increase_liquidity_v2(data, input_accounts, balances)
         }
         OrcaInstructions::DecreaseLiquidity(data, input_accounts) => {
-            process_decrease_liquidity(data, input_accounts, confirmed_txn)
+            process_decrease_liquidity(data, input_accounts, balances)
         }
         OrcaInstructions::DecreaseLiquidityV2(data, input_accounts) => {
-            process_decrease_liquidity_v2(data, input_accounts, confirmed_txn)
+            process_decrease_liquidity_v2(data, input_accounts, balances)
         }
         OrcaInstructions::TwoHopSwap(data, input_accounts) => {
-            process_two_hop_swap(data, input_accounts, confirmed_txn)
+            process_two_hop_swap(data, input_accounts, balances)
         }
         OrcaInstructions::TwoHopSwapV2(data, input_accounts) => {
-            process_two_hop_swap_v2(data, input_accounts, confirmed_txn)
+            process_two_hop_swap_v2(data, input_accounts, balances)
         }
         OrcaInstructions::Swap(data, input_accounts) => {
-            process_swap(data, input_accounts, confirmed_txn)
+            process_swap(data, input_accounts, balances)
         }
         OrcaInstructions::SwapV2(data, input_accounts) => {
-            process_swap_v2(data, input_accounts, confirmed_txn)
+            process_swap_v2(data, input_accounts, balances)
+        }
+        OrcaInstructions::OpenPosition(data, input_accounts) => {
+            process_open_position(data, input_accounts)
+        }
+        OrcaInstructions::OpenPositionWithMetadata(data, input_accounts) => {
+            process_open_position_with_metadata(data, input_accounts)
+        }
+        OrcaInstructions::OpenPositionWithTokenExtensions(data, input_accounts) => {
+            process_open_position_with_token_extensions(data, input_accounts)
+        }
+        OrcaInstructions::ClosePosition(data, input_accounts) => {
+            process_close_position(data, input_accounts)
+        }
+        OrcaInstructions::ClosePositionWithTokenExtensions(data, input_accounts) => {
+            process_close_position_with_token_extensions(data, input_accounts)
+        }
+        OrcaInstructions::InitializePositionBundle(data, input_accounts) => {
+            process_initialize_position_bundle(data, input_accounts)
+        }
+        OrcaInstructions::InitializePositionBundleWithMetadata(data, input_accounts) => {
+            process_initialize_position_bundle_with_metadata(data, input_accounts)
+        }
+        OrcaInstructions::OpenBundledPosition(data, input_accounts) => {
+            process_open_bundled_position(data, input_accounts)
+        }
+        OrcaInstructions::CloseBundledPosition(data, input_accounts) => {
+            process_close_bundled_position(data, input_accounts)
+        }
+        OrcaInstructions::CollectFees(data, input_accounts) => {
+            process_collect_fees(data, input_accounts, balances)
+        }
+        OrcaInstructions::CollectFeesV2(data, input_accounts) => {
+            process_collect_fees_v2(data, input_accounts, balances)
+        }
+        OrcaInstructions::InitializeReward(data, input_accounts) => {
+            process_initialize_reward(data, input_accounts)
+        }
+        OrcaInstructions::InitializeRewardV2(data, input_accounts) => {
+            process_initialize_reward_v2(data, input_accounts)
+        }
+        OrcaInstructions::SetRewardEmissions(data, input_accounts) => {
+            process_set_reward_emissions(data, input_accounts)
+        }
+        OrcaInstructions::SetRewardEmissionsV2(data, input_accounts) => {
+            process_set_reward_emissions_v2(data, input_accounts)
+        }
+        OrcaInstructions::CollectReward(data, input_accounts) => {
+            process_collect_reward(data, input_accounts, balances)
+        }
+        OrcaInstructions::CollectRewardV2(data, input_accounts) => {
+            process_collect_reward_v2(data, input_accounts, balances)
+        }
+        OrcaInstructions::InitializeConfig(data, input_accounts) => {
+            process_initialize_config(data, input_accounts)
+        }
+        OrcaInstructions::InitializeFeeTier(data, input_accounts) => {
+            process_initialize_fee_tier(data, input_accounts)
+        }
+        OrcaInstructions::SetDefaultFeeRate(data, input_accounts) => {
+            process_set_default_fee_rate(data, input_accounts)
+        }
+        OrcaInstructions::SetDefaultProtocolFeeRate(data, input_accounts) => {
+            process_set_default_protocol_fee_rate(data, input_accounts)
+        }
+        OrcaInstructions::SetFeeRate(data, input_accounts) => {
+            process_set_fee_rate(data, input_accounts)
+        }
+        OrcaInstructions::SetProtocolFeeRate(data, input_accounts) => {
+            process_set_protocol_fee_rate(data, input_accounts)
         }
     };
 
-    instr_type.map(|r#type| Event {
+    instr_type.map(|r#type| {
+        event(
+            r#type,
+            confirmed_txn,
+            anchor_events,
+            block,
+            instruction_index,
+            inner_instruction_index,
+        )
+    })
+}
+
+fn event(
+    r#type: Type,
+    confirmed_txn: &ConfirmedTransaction,
+    anchor_events: Vec<AnchorEvent>,
+    block: &Block,
+    instruction_index: u32,
+    inner_instruction_index: u32,
+) -> Event {
+    Event {
         slot: block.slot,
         txn_id: confirmed_txn.id().clone(),
         block_height: block.block_height.clone().unwrap_or_default().block_height,
         block_timestamp: block.block_time.clone().unwrap_or_default().timestamp,
         block_hash: block.blockhash.clone(),
+        instruction_index,
+        inner_instruction_index,
+        anchor_events,
         r#type: Some(r#type),
-    })
+    }
 }
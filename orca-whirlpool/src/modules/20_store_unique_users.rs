@@ -1,40 +1,110 @@
 use substreams::pb::substreams::Clock;
 use substreams::scalar::BigInt;
 use substreams::skip_empty_output;
-use substreams::store::{StoreDelete, StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsBigInt};
+use substreams::store::{
+    DeltaInt64, Deltas, StoreGet, StoreGetString, StoreNew, StoreSetIfNotExists,
+    StoreSetIfNotExistsBigInt,
+};
 
-use crate::key_store::StoreKey;
+use crate::key_store::{Granularity, StoreKey};
 use crate::pb::messari::orca_whirlpool::v1::{event::Type, Events};
+use crate::utils;
 
 #[substreams::handlers::store]
-pub fn store_unique_users(clock: Clock, raw_events: Events, store: StoreSetIfNotExistsBigInt) {
+pub fn store_unique_users(
+    clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
+    raw_events: Events,
+    position_owners_store: StoreGetString,
+    store: StoreSetIfNotExistsBigInt,
+) {
     skip_empty_output();
 
     let bigint1 = BigInt::one();
-    let day_id = clock.timestamp.unwrap().seconds / 86400;
+    let block_number = BigInt::from(clock.number);
+    let seconds = clock.timestamp.unwrap().seconds;
 
-    // Delete previous day's snapshot
-    store.delete_prefix(
-        0,
-        &StoreKey::UsageMetricsDailySnapshot(day_id - 1, None).unique_id(),
-    );
+    // Delete the snapshots of the days and hours rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, period_id| {
+        Some(granularity.usage_metrics_snapshot(period_id, None))
+    });
 
     for event in raw_events.data {
-        if let Some(user_address) = get_user_address(event.r#type.unwrap()) {
-            set_user_activity(&store, day_id, &user_address, &bigint1);
+        let event_type = event.r#type.unwrap();
+        let activity = match get_user_activity(&event_type) {
+            Some(activity) => activity,
+            None => continue,
+        };
+
+        if let Some(user_address) = get_user_address(event_type, &position_owners_store) {
+            set_user_activity(
+                &store,
+                seconds,
+                &block_number,
+                &user_address,
+                activity,
+                &bigint1,
+            );
+        }
+    }
+}
+
+/// The roles a user takes on through `event`, and the active users of its period it
+/// counts towards. Depositors and withdrawers are both liquidity providers.
+fn get_user_activity(event: &Type) -> Option<(Vec<StoreKey>, StoreKey)> {
+    match event {
+        Type::IncreaseLiquidity(_) | Type::IncreaseLiquidityV2(_) => Some((
+            vec![StoreKey::LiquidityProvider, StoreKey::Depositor],
+            StoreKey::ActiveDepositors,
+        )),
+        Type::DecreaseLiquidity(_) | Type::DecreaseLiquidityV2(_) => Some((
+            vec![StoreKey::LiquidityProvider, StoreKey::Withdrawer],
+            StoreKey::ActiveWithdrawers,
+        )),
+        Type::TwoHopSwap(_) | Type::TwoHopSwapV2(_) | Type::Swap(_) | Type::SwapV2(_) => {
+            Some((vec![StoreKey::Trader], StoreKey::ActiveTraders))
         }
+        _ => None,
     }
 }
 
-fn get_user_address(event: Type) -> Option<String> {
+fn get_user_address(event: Type, position_owners_store: &StoreGetString) -> Option<String> {
     match event {
         // increase liquidity
-        Type::IncreaseLiquidity(e) => Some(e.accounts.as_ref()?.position_authority.clone()),
-        Type::IncreaseLiquidityV2(e) => Some(e.accounts.as_ref()?.position_authority.clone()),
+        Type::IncreaseLiquidity(e) => {
+            let accounts = e.accounts?;
+            Some(utils::position_owner(
+                &accounts.position,
+                accounts.position_authority,
+                position_owners_store,
+            ))
+        }
+        Type::IncreaseLiquidityV2(e) => {
+            let accounts = e.accounts?;
+            Some(utils::position_owner(
+                &accounts.position,
+                accounts.position_authority,
+                position_owners_store,
+            ))
+        }
 
         // decrease liquidity
-        Type::DecreaseLiquidity(e) => Some(e.accounts.as_ref()?.position_authority.clone()),
-        Type::DecreaseLiquidityV2(e) => Some(e.accounts.as_ref()?.position_authority.clone()),
+        Type::DecreaseLiquidity(e) => {
+            let accounts = e.accounts?;
+            Some(utils::position_owner(
+                &accounts.position,
+                accounts.position_authority,
+                position_owners_store,
+            ))
+        }
+        Type::DecreaseLiquidityV2(e) => {
+            let accounts = e.accounts?;
+            Some(utils::position_owner(
+                &accounts.position,
+                accounts.position_authority,
+                position_owners_store,
+            ))
+        }
 
         // two hop swap
         Type::TwoHopSwap(e) => Some(e.accounts.as_ref()?.token_authority.clone()),
@@ -47,36 +117,50 @@ fn get_user_address(event: Type) -> Option<String> {
     }
 }
 
-/// Sets user activity in the store for a given day and user address.
+/// Sets user activity in the store for a given timestamp and user address.
 ///
-/// This function records user activity in two places:
-/// 1. A general user record
-/// 2. A daily snapshot of active users
+/// This function records user activity in three places:
+/// 1. A general user record, holding the block in which the user was first seen, and a
+///    record of each of the user's roles (liquidity provider, depositor, withdrawer or trader)
+/// 2. A daily snapshot of active users, overall and for the action taken
+/// 3. An hourly snapshot of active users, overall and for the action taken
 ///
 /// # Arguments
 ///
 /// * `store` - The store to set the values in
-/// * `day_id` - The ID of the day for which to record the activity
+/// * `seconds` - The timestamp of the block in which the activity occurred
+/// * `block_number` - The number of the block in which the activity occurred
 /// * `user_address` - The address of the user whose activity is being recorded
-/// * `value` - The value to set (typically 1, indicating activity)
+/// * `(roles, active_key)` - The roles taken on by the user, and the active users they count towards
+/// * `value` - The value to set in the snapshots (typically 1, indicating activity)
 ///
 /// # Note
 ///
-/// This function uses `set_if_not_exists_many` to ensure that each user is only counted once per day,
-/// even if they perform multiple activities.
+/// This function uses `set_if_not_exists_many` to ensure that each user is only counted once per day
+/// and per hour, even if they perform multiple activities.
 fn set_user_activity(
     store: &StoreSetIfNotExistsBigInt,
-    day_id: i64,
+    seconds: i64,
+    block_number: &BigInt,
     user_address: &str,
+    (roles, active_key): (Vec<StoreKey>, StoreKey),
     value: &BigInt,
 ) {
-    store.set_if_not_exists_many(
-        0,
-        &vec![
-            StoreKey::User.get_unique_key(user_address),
-            StoreKey::UsageMetricsDailySnapshot(day_id, Some(Box::new(StoreKey::ActiveUsers)))
-                .get_snapshot_key(Some(user_address)),
-        ],
-        value,
-    );
+    store.set_if_not_exists(0, StoreKey::User.get_unique_key(user_address), block_number);
+
+    let mut keys: Vec<String> = roles
+        .iter()
+        .map(|role| role.get_unique_key(user_address))
+        .collect();
+    keys.extend(Granularity::ALL.into_iter().flat_map(|granularity| {
+        let period_id = granularity.period_id(seconds);
+
+        [StoreKey::ActiveUsers, active_key.clone()].map(|field| {
+            granularity
+                .usage_metrics_snapshot(period_id, Some(field))
+                .get_snapshot_key(Some(user_address))
+        })
+    }));
+
+    store.set_if_not_exists_many(0, &keys, value);
 }
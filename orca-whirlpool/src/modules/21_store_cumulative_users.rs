@@ -1,35 +1,66 @@
+use substreams::pb::substreams::store_delta::Operation;
 use substreams::pb::substreams::Clock;
 use substreams::skip_empty_output;
-use substreams::store::{DeltaBigInt, Deltas, StoreAddInt64, StoreDelete};
+use substreams::store::{DeltaBigInt, DeltaExt, DeltaInt64, Deltas, StoreAddInt64};
 use substreams::store::{StoreAdd, StoreNew};
 
-use crate::key_store::StoreKey;
+use crate::key_store::{Granularity, StoreKey};
+use crate::utils;
 
 #[substreams::handlers::store]
 pub fn store_cumulative_users(
     clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
     unique_users_delta: Deltas<DeltaBigInt>,
     store: StoreAddInt64,
 ) {
     skip_empty_output();
 
-    let day_id = clock.timestamp.unwrap().seconds / 86400;
-
-    // Delete previous day's snapshot
-    store.delete_prefix(
-        0,
-        &StoreKey::UsageMetricsDailySnapshot(day_id - 1, None).unique_id(),
-    );
-
-    for _ in unique_users_delta.deltas {
-        store.add_many(
-            0,
-            &vec![
-                StoreKey::CumulativeUsers.unique_id(),
-                StoreKey::UsageMetricsDailySnapshot(day_id, Some(Box::new(StoreKey::ActiveUsers)))
-                    .get_snapshot_key(None),
-            ],
-            1,
-        );
-    }
+    let seconds = clock.timestamp.unwrap().seconds;
+
+    // Delete the snapshots of the days and hours rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, period_id| {
+        Some(granularity.usage_metrics_snapshot(period_id, None))
+    });
+
+    // Each user is set once overall, once per role and once per period and action, so
+    // every created key counts towards its own total.
+    [
+        (StoreKey::User, StoreKey::CumulativeUsers),
+        (StoreKey::LiquidityProvider, StoreKey::CumulativeUniqueLPs),
+        (StoreKey::Depositor, StoreKey::CumulativeUniqueDepositors),
+        (StoreKey::Withdrawer, StoreKey::CumulativeUniqueWithdrawers),
+        (StoreKey::Trader, StoreKey::CumulativeUniqueTraders),
+    ]
+    .into_iter()
+    .for_each(|(user_key, cumulative_key)| {
+        unique_users_delta
+            .iter()
+            .key_first_segment_eq(user_key.unique_id())
+            .operation_eq(Operation::Create)
+            .for_each(|_| store.add(0, cumulative_key.unique_id(), 1));
+    });
+
+    Granularity::ALL.into_iter().for_each(|granularity| {
+        let period_id = granularity.period_id(seconds);
+
+        [
+            StoreKey::ActiveUsers,
+            StoreKey::ActiveDepositors,
+            StoreKey::ActiveWithdrawers,
+            StoreKey::ActiveTraders,
+        ]
+        .into_iter()
+        .for_each(|field| {
+            let active_key = granularity
+                .usage_metrics_snapshot(period_id, Some(field))
+                .get_snapshot_key(None);
+
+            unique_users_delta
+                .iter()
+                .operation_eq(Operation::Create)
+                .filter(|delta| delta.key.starts_with(&active_key))
+                .for_each(|_| store.add(0, &active_key, 1));
+        });
+    });
 }
@@ -3,13 +3,14 @@ use substreams::skip_empty_output;
 use substreams::store::{StoreNew, StoreSet, StoreSetBigInt};
 
 use crate::key_store::StoreKey;
-use crate::pb::messari::orca_whirlpool::v1::{Deposits, Swaps, Withdraws};
+use crate::pb::messari::orca_whirlpool::v1::{Deposits, FeeCollections, Swaps, Withdraws};
 
 #[substreams::handlers::store]
 pub fn store_pool_balances(
     pool_deposits: Deposits,
     pool_withdraws: Withdraws,
     pool_swaps: Swaps,
+    pool_fee_collections: FeeCollections,
     store: StoreSetBigInt,
 ) {
     skip_empty_output();
@@ -58,6 +59,21 @@ pub fn store_pool_balances(
             ),
         ]
     });
+
+    process_pool_balances(&pool_fee_collections.data, &store, |item| {
+        vec![
+            (
+                item.pool.clone(),
+                item.token_a.clone(),
+                item.token_a_balance.clone(),
+            ),
+            (
+                item.pool.clone(),
+                item.token_b.clone(),
+                item.token_b_balance.clone(),
+            ),
+        ]
+    });
 }
 
 /// Processes pool balances for a given set of items.
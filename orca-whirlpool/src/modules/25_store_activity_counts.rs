@@ -1,14 +1,16 @@
 use substreams::pb::substreams::Clock;
 use substreams::scalar::BigInt;
 use substreams::skip_empty_output;
-use substreams::store::{StoreAdd, StoreAddBigInt, StoreDelete, StoreNew};
+use substreams::store::{DeltaInt64, Deltas, StoreAdd, StoreAddBigInt, StoreNew};
 
-use crate::key_store::StoreKey;
+use crate::key_store::{Granularity, StoreKey};
 use crate::pb::messari::orca_whirlpool::v1::{Deposits, Swaps, Withdraws};
+use crate::utils;
 
 #[substreams::handlers::store]
 pub fn store_activity_counts(
     clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
     pool_deposits: Deposits,
     pool_withdraws: Withdraws,
     pool_swaps: Swaps,
@@ -16,25 +18,38 @@ pub fn store_activity_counts(
 ) {
     skip_empty_output();
 
-    let day_id = clock.timestamp.unwrap().seconds / 86400;
+    let seconds = clock.timestamp.unwrap().seconds;
 
-    // Delete previous day's snapshot
-    store.delete_prefix(
-        0,
-        &StoreKey::UsageMetricsDailySnapshot(day_id - 1, None).unique_id(),
-    );
+    // Delete the snapshots of the days and hours rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, period_id| {
+        Some(granularity.usage_metrics_snapshot(period_id, None))
+    });
 
-    // Update the counts for the day, and the total transaction count.
+    // Update the cumulative counts, the counts for the day and the hour, and their total
+    // transaction counts.
     let update_counts = |count_type: StoreKey| {
         store.add_many(
             0,
-            &vec![
-                StoreKey::UsageMetricsDailySnapshot(day_id, Some(Box::new(count_type))).unique_id(),
-                StoreKey::UsageMetricsDailySnapshot(day_id, Some(Box::new(StoreKey::TxnCount)))
-                    .unique_id(),
-            ],
+            &vec![count_type.unique_id(), StoreKey::TxnCount.unique_id()],
             &BigInt::one(),
         );
+
+        Granularity::ALL.into_iter().for_each(|granularity| {
+            let period_id = granularity.period_id(seconds);
+
+            store.add_many(
+                0,
+                &vec![
+                    granularity
+                        .usage_metrics_snapshot(period_id, Some(count_type.clone()))
+                        .unique_id(),
+                    granularity
+                        .usage_metrics_snapshot(period_id, Some(StoreKey::TxnCount))
+                        .unique_id(),
+                ],
+                &BigInt::one(),
+            );
+        });
     };
 
     pool_deposits
@@ -45,8 +60,20 @@ pub fn store_activity_counts(
         .data
         .iter()
         .for_each(|_| update_counts(StoreKey::WithdrawCount));
-    pool_swaps
-        .data
-        .iter()
-        .for_each(|_| update_counts(StoreKey::SwapCount));
+    pool_swaps.data.iter().for_each(|swap| {
+        update_counts(StoreKey::SwapCount);
+
+        [
+            (&swap.token_in, &swap.amount_in),
+            (&swap.token_out, &swap.amount_out),
+        ]
+        .into_iter()
+        .for_each(|(token, amount)| {
+            store.add(
+                0,
+                StoreKey::ProtocolVolumeByTokenAmount.get_unique_key(token),
+                BigInt::try_from(amount).unwrap_or_default().absolute(),
+            );
+        });
+    });
 }
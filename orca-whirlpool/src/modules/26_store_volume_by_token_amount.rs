@@ -1,40 +1,64 @@
 use substreams::pb::substreams::Clock;
 use substreams::scalar::BigInt;
 use substreams::skip_empty_output;
-use substreams::store::{StoreAdd, StoreAddBigInt, StoreDelete, StoreNew};
+use substreams::store::{DeltaInt64, Deltas, StoreAdd, StoreAddBigInt, StoreNew};
 
-use crate::key_store::StoreKey;
+use crate::key_store::{Granularity, StoreKey};
 use crate::pb::messari::orca_whirlpool::v1::Swaps;
+use crate::utils;
 
 #[substreams::handlers::store]
-pub fn store_volume_by_token_amount(clock: Clock, pool_swaps: Swaps, store: StoreAddBigInt) {
+pub fn store_volume_by_token_amount(
+    clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
+    pool_swaps: Swaps,
+    store: StoreAddBigInt,
+) {
     skip_empty_output();
 
-    let day_id = clock.timestamp.unwrap().seconds / 86400;
+    let seconds = clock.timestamp.unwrap().seconds;
 
-    // Delete previous day's snapshot
-    store.delete_prefix(
-        0,
-        &StoreKey::PoolDailySnapshot(day_id - 1, None).unique_id(),
-    );
+    // Delete the snapshots of the days and hours rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, period_id| {
+        Some(granularity.pool_snapshot(period_id, None))
+    });
 
     pool_swaps.data.iter().for_each(|swap| {
+        let amount_in = BigInt::try_from(&swap.amount_in)
+            .unwrap_or_default()
+            .absolute();
+        let amount_out = BigInt::try_from(&swap.amount_out)
+            .unwrap_or_default()
+            .absolute();
+
         store.add(
             0,
-            StoreKey::PoolDailySnapshot(day_id, Some(Box::new(StoreKey::DailyVolumeByTokenAmount)))
-                .get_unique_keys(&swap.to, &swap.token_in),
-            BigInt::try_from(&swap.amount_in)
-                .unwrap_or_default()
-                .absolute(),
+            StoreKey::VolumeByTokenAmount.get_unique_keys(&swap.to, &swap.token_in),
+            &amount_in,
         );
-
         store.add(
             0,
-            StoreKey::PoolDailySnapshot(day_id, Some(Box::new(StoreKey::DailyVolumeByTokenAmount)))
-                .get_unique_keys(&swap.to, &swap.token_out),
-            BigInt::try_from(&swap.amount_out)
-                .unwrap_or_default()
-                .absolute(),
+            StoreKey::VolumeByTokenAmount.get_unique_keys(&swap.to, &swap.token_out),
+            &amount_out,
         );
+
+        Granularity::ALL.into_iter().for_each(|granularity| {
+            let snapshot = granularity.pool_snapshot(
+                granularity.period_id(seconds),
+                Some(granularity.volume_by_token_amount()),
+            );
+
+            store.add(
+                0,
+                snapshot.get_unique_keys(&swap.to, &swap.token_in),
+                &amount_in,
+            );
+
+            store.add(
+                0,
+                snapshot.get_unique_keys(&swap.to, &swap.token_out),
+                &amount_out,
+            );
+        });
     });
 }
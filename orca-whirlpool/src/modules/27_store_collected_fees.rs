@@ -0,0 +1,47 @@
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::skip_empty_output;
+use substreams::store::{DeltaInt64, Deltas, StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::key_store::{Granularity, StoreKey};
+use crate::pb::messari::orca_whirlpool::v1::FeeCollections;
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_collected_fees(
+    clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
+    fee_collections: FeeCollections,
+    store: StoreAddBigInt,
+) {
+    skip_empty_output();
+
+    let day_id = Granularity::Daily.period_id(clock.timestamp.unwrap().seconds);
+
+    // Delete the snapshot of the day rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, day_id| {
+        granularity.daily_snapshot(StoreKey::PoolDailySnapshot(day_id, None))
+    });
+
+    fee_collections.data.iter().for_each(|fee_collection| {
+        [
+            (&fee_collection.token_a, &fee_collection.amount_a),
+            (&fee_collection.token_b, &fee_collection.amount_b),
+        ]
+        .into_iter()
+        .for_each(|(token, amount)| {
+            store.add_many(
+                0,
+                &vec![
+                    StoreKey::CollectedFees.get_unique_keys(&fee_collection.pool, token),
+                    StoreKey::PoolDailySnapshot(
+                        day_id,
+                        Some(Box::new(StoreKey::DailyCollectedFees)),
+                    )
+                    .get_unique_keys(&fee_collection.pool, token),
+                ],
+                BigInt::try_from(amount).unwrap_or_default(),
+            );
+        });
+    });
+}
@@ -0,0 +1,35 @@
+use substreams::scalar::BigInt;
+use substreams::skip_empty_output;
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreDelete, StoreNew};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{Positions, Rewards};
+
+#[substreams::handlers::store]
+pub fn store_collected_rewards(positions: Positions, rewards: Rewards, store: StoreAddBigInt) {
+    skip_empty_output();
+
+    // A reopened bundled position starts over with no collected rewards.
+    positions
+        .data
+        .iter()
+        .filter(|p| !p.is_closed)
+        .for_each(|position| {
+            store.delete_prefix(
+                0,
+                &StoreKey::PositionCollectedRewards.get_unique_key(&position.address),
+            );
+        });
+
+    rewards.collections.iter().for_each(|collection| {
+        store.add_many(
+            0,
+            &vec![
+                StoreKey::CollectedRewards.get_unique_key(&collection.reward_token),
+                StoreKey::PositionCollectedRewards
+                    .get_unique_keys(&collection.position, &collection.reward_token),
+            ],
+            BigInt::try_from(&collection.amount).unwrap_or_default(),
+        );
+    });
+}
@@ -0,0 +1,57 @@
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::skip_empty_output;
+use substreams::store::{DeltaInt64, Deltas, StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::key_store::{Granularity, StoreKey};
+use crate::pb::messari::orca_whirlpool::v1::Swaps;
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_swap_fees(
+    clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
+    pool_swaps: Swaps,
+    store: StoreAddBigInt,
+) {
+    skip_empty_output();
+
+    let day_id = Granularity::Daily.period_id(clock.timestamp.unwrap().seconds);
+
+    // Delete the snapshot of the day rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, day_id| {
+        granularity.daily_snapshot(StoreKey::PoolDailySnapshot(day_id, None))
+    });
+
+    pool_swaps.data.iter().for_each(|swap| {
+        [
+            (
+                StoreKey::TotalRevenue,
+                StoreKey::DailyTotalRevenue,
+                &swap.fee_amount,
+            ),
+            (
+                StoreKey::SupplySideRevenue,
+                StoreKey::DailySupplySideRevenue,
+                &swap.lp_fee_amount,
+            ),
+            (
+                StoreKey::ProtocolSideRevenue,
+                StoreKey::DailyProtocolSideRevenue,
+                &swap.protocol_fee_amount,
+            ),
+        ]
+        .into_iter()
+        .for_each(|(cumulative_key, daily_key, amount)| {
+            store.add_many(
+                0,
+                &vec![
+                    cumulative_key.get_unique_keys(&swap.to, &swap.token_in),
+                    StoreKey::PoolDailySnapshot(day_id, Some(Box::new(daily_key)))
+                        .get_unique_keys(&swap.to, &swap.token_in),
+                ],
+                BigInt::try_from(amount).unwrap_or_default(),
+            );
+        });
+    });
+}
@@ -1,4 +1,6 @@
-use crate::pb::messari::orca_whirlpool::v1::{event, Events, Pool, Pools};
+use crate::pb::messari::orca_whirlpool::v1::{
+    anchor_event, event, AnchorEvent, Events, Pool, PoolInitialized, Pools,
+};
 use substreams::skip_empty_output;
 
 #[substreams::handlers::map]
@@ -10,7 +12,10 @@ pub fn map_pools(raw_events: Events) -> Result<Pools, substreams::errors::Error>
         .into_iter()
         .filter_map(|event| match event.r#type {
             Some(event::Type::InitializePool(initialize_pool_event)) => {
-                let accounts = initialize_pool_event.accounts.unwrap();
+                let (instruction, accounts) = (
+                    initialize_pool_event.instruction.unwrap(),
+                    initialize_pool_event.accounts.unwrap(),
+                );
                 Some(Pool {
                     address: accounts.whirlpool,
                     token_mint_a: accounts.token_mint_a,
@@ -19,10 +24,24 @@ pub fn map_pools(raw_events: Events) -> Result<Pools, substreams::errors::Error>
                     token_vault_b: accounts.token_vault_b,
                     created_timestamp: event.block_timestamp,
                     created_block_number: event.block_height,
+                    whirlpools_config: accounts.whirlpools_config,
+                    fee_tier: accounts.fee_tier,
+                    tick_spacing: instruction.tick_spacing,
+                    initial_sqrt_price: instruction.initial_sqrt_price,
+                    // V1 pools only support the original token program.
+                    token_program_a: accounts.token_program.clone(),
+                    token_program_b: accounts.token_program,
+                    decimals_a: initialized_decimals(&event.anchor_events, |e| e.decimals_a)
+                        .or(instruction.decimals_a),
+                    decimals_b: initialized_decimals(&event.anchor_events, |e| e.decimals_b)
+                        .or(instruction.decimals_b),
                 })
             }
             Some(event::Type::InitializePoolV2(initialize_pool_v2_event)) => {
-                let accounts = initialize_pool_v2_event.accounts.unwrap();
+                let (instruction, accounts) = (
+                    initialize_pool_v2_event.instruction.unwrap(),
+                    initialize_pool_v2_event.accounts.unwrap(),
+                );
                 Some(Pool {
                     address: accounts.whirlpool,
                     token_mint_a: accounts.token_mint_a,
@@ -31,6 +50,16 @@ pub fn map_pools(raw_events: Events) -> Result<Pools, substreams::errors::Error>
                     token_vault_b: accounts.token_vault_b,
                     created_timestamp: event.block_timestamp,
                     created_block_number: event.block_height,
+                    whirlpools_config: accounts.whirlpools_config,
+                    fee_tier: accounts.fee_tier,
+                    tick_spacing: instruction.tick_spacing,
+                    initial_sqrt_price: instruction.initial_sqrt_price,
+                    token_program_a: accounts.token_program_a,
+                    token_program_b: accounts.token_program_b,
+                    decimals_a: initialized_decimals(&event.anchor_events, |e| e.decimals_a)
+                        .or(instruction.decimals_a),
+                    decimals_b: initialized_decimals(&event.anchor_events, |e| e.decimals_b)
+                        .or(instruction.decimals_b),
                 })
             }
             _ => None,
@@ -39,3 +68,19 @@ pub fn map_pools(raw_events: Events) -> Result<Pools, substreams::errors::Error>
 
     Ok(Pools { data })
 }
+
+/// Reads token decimals from the `PoolInitialized` event log, which reports them exactly,
+/// rather than from the balances of the transaction.
+fn initialized_decimals(
+    anchor_events: &[AnchorEvent],
+    decimals: fn(&PoolInitialized) -> u32,
+) -> Option<u32> {
+    anchor_events
+        .iter()
+        .find_map(|anchor_event| match &anchor_event.r#type {
+            Some(anchor_event::Type::PoolInitialized(pool_initialized)) => {
+                Some(decimals(pool_initialized))
+            }
+            _ => None,
+        })
+}
@@ -0,0 +1,29 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSetIfNotExists, StoreSetIfNotExistsProto};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{Pools, Token};
+
+#[substreams::handlers::store]
+pub fn store_tokens(initialized_pools: Pools, store: StoreSetIfNotExistsProto<Token>) {
+    skip_empty_output();
+
+    for pool in initialized_pools.data {
+        let tokens = [
+            (pool.token_mint_a, pool.decimals_a, pool.token_program_a),
+            (pool.token_mint_b, pool.decimals_b, pool.token_program_b),
+        ];
+
+        for (address, decimals, token_program) in tokens {
+            store.set_if_not_exists(
+                0,
+                StoreKey::Token.get_unique_key(&address),
+                &Token {
+                    address,
+                    decimals,
+                    token_program,
+                },
+            );
+        }
+    }
+}
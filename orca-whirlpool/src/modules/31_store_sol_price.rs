@@ -0,0 +1,67 @@
+use substreams::key;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::BigDecimal;
+use substreams::skip_empty_output;
+use substreams::store::{
+    DeltaExt, DeltaProto, Deltas, StoreGet, StoreGetBigInt, StoreGetProto, StoreNew, StoreSet,
+    StoreSetBigDecimal,
+};
+
+use crate::constants::{MINIMUM_SOL_PRICE_LIQUIDITY_USD, WRAPPED_SOL};
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{Pool, PoolPrice};
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_sol_price(
+    pool_prices_deltas: Deltas<DeltaProto<PoolPrice>>,
+    pool_store: StoreGetProto<Pool>,
+    pool_balances_store: StoreGetBigInt,
+    store: StoreSetBigDecimal,
+) {
+    skip_empty_output();
+
+    pool_prices_deltas
+        .iter()
+        .key_first_segment_eq(StoreKey::PoolPrice.unique_id())
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let pool_address = key::segment_at(&delta.key, 1);
+            let pool = match pool_store.get_last(StoreKey::Pool.get_unique_key(pool_address)) {
+                Some(pool) => pool,
+                None => return,
+            };
+
+            // Balance ratios ignore the concentrated liquidity of the pool, so only
+            // logged prices are trusted.
+            if delta.new_value.approximated {
+                return;
+            }
+
+            let (sol_price, stablecoin) = if pool.token_mint_a == WRAPPED_SOL
+                && utils::is_stablecoin(&pool.token_mint_b)
+            {
+                (&delta.new_value.token_a_price, &pool.token_mint_b)
+            } else if pool.token_mint_b == WRAPPED_SOL && utils::is_stablecoin(&pool.token_mint_a) {
+                (&delta.new_value.token_b_price, &pool.token_mint_a)
+            } else {
+                return;
+            };
+
+            let liquidity_usd = utils::pool_balance_usd(
+                &pool,
+                stablecoin,
+                &BigDecimal::one(),
+                &pool_balances_store,
+            );
+            if liquidity_usd < BigDecimal::from(MINIMUM_SOL_PRICE_LIQUIDITY_USD) {
+                return;
+            }
+
+            store.set(
+                0,
+                StoreKey::TokenPrice.get_unique_key(WRAPPED_SOL),
+                &BigDecimal::try_from(sol_price.clone()).unwrap_or_else(|_| BigDecimal::zero()),
+            );
+        });
+}
@@ -0,0 +1,94 @@
+use substreams::key;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::BigDecimal;
+use substreams::skip_empty_output;
+use substreams::store::{
+    DeltaBigDecimal, DeltaExt, DeltaProto, Deltas, StoreGet, StoreGetBigDecimal, StoreGetBigInt,
+    StoreGetProto, StoreNew, StoreSet, StoreSetBigDecimal,
+};
+
+use crate::constants::{MINIMUM_PRICE_LIQUIDITY_USD, WRAPPED_SOL};
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{Pool, PoolPrice};
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_token_prices(
+    pool_prices_deltas: Deltas<DeltaProto<PoolPrice>>,
+    pool_store: StoreGetProto<Pool>,
+    pool_balances_store: StoreGetBigInt,
+    sol_price_store: StoreGetBigDecimal,
+    sol_price_deltas: Deltas<DeltaBigDecimal>,
+    store: StoreSetBigDecimal,
+) {
+    skip_empty_output();
+
+    // Wrapped SOL is priced from its stablecoin pools by `store_sol_price`.
+    sol_price_deltas
+        .iter()
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| store.set(0, &delta.key, &delta.new_value));
+
+    pool_prices_deltas
+        .iter()
+        .key_first_segment_eq(StoreKey::PoolPrice.unique_id())
+        .operation_not_eq(Operation::Delete)
+        .for_each(|delta| {
+            let pool_address = key::segment_at(&delta.key, 1);
+            let pool = match pool_store.get_last(StoreKey::Pool.get_unique_key(pool_address)) {
+                Some(pool) => pool,
+                None => return,
+            };
+
+            // Balance ratios ignore the concentrated liquidity of the pool, so only
+            // logged prices are trusted.
+            if delta.new_value.approximated {
+                return;
+            }
+
+            // Each token of the pool is priced in the other one, when that one is a
+            // reference token with enough liquidity in the pool.
+            let pairs = [
+                (
+                    &pool.token_mint_a,
+                    &pool.token_mint_b,
+                    &delta.new_value.token_a_price,
+                ),
+                (
+                    &pool.token_mint_b,
+                    &pool.token_mint_a,
+                    &delta.new_value.token_b_price,
+                ),
+            ];
+
+            for (token, reference, price_in_reference) in pairs {
+                if utils::is_stablecoin(token) || token == WRAPPED_SOL {
+                    continue;
+                }
+
+                let reference_price = match utils::token_price_usd(reference, &sol_price_store) {
+                    Some(reference_price) => reference_price,
+                    None => continue,
+                };
+
+                let liquidity_usd = utils::pool_balance_usd(
+                    &pool,
+                    reference,
+                    &reference_price,
+                    &pool_balances_store,
+                );
+                if liquidity_usd < BigDecimal::from(MINIMUM_PRICE_LIQUIDITY_USD) {
+                    continue;
+                }
+
+                let price_in_reference = BigDecimal::try_from(price_in_reference.clone())
+                    .unwrap_or_else(|_| BigDecimal::zero());
+
+                store.set(
+                    0,
+                    StoreKey::TokenPrice.get_unique_key(token),
+                    &(price_in_reference * reference_price),
+                );
+            }
+        });
+}
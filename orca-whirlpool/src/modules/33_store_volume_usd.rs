@@ -0,0 +1,60 @@
+use substreams::log;
+use substreams::pb::substreams::Clock;
+use substreams::skip_empty_output;
+use substreams::store::{
+    DeltaInt64, Deltas, StoreAdd, StoreAddBigDecimal, StoreGet, StoreGetBigDecimal, StoreGetProto,
+    StoreNew,
+};
+
+use crate::key_store::{Granularity, StoreKey};
+use crate::pb::messari::orca_whirlpool::v1::{Pool, Swaps};
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_volume_usd(
+    clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
+    pool_swaps: Swaps,
+    pool_store: StoreGetProto<Pool>,
+    token_prices_store: StoreGetBigDecimal,
+    store: StoreAddBigDecimal,
+) {
+    skip_empty_output();
+
+    let seconds = clock.timestamp.unwrap().seconds;
+
+    // Delete the snapshots of the days and hours rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, period_id| {
+        Some(granularity.pool_snapshot(period_id, None))
+    });
+
+    pool_swaps.data.iter().for_each(|swap| {
+        let pool = match pool_store.get_last(StoreKey::Pool.get_unique_key(&swap.to)) {
+            Some(pool) => pool,
+            None => {
+                log::info!("Pool not found: {:?}", swap.to);
+                return;
+            }
+        };
+
+        let volume_usd = utils::swap_volume_usd(&pool, swap, &token_prices_store);
+
+        store.add(
+            0,
+            StoreKey::VolumeUSD.get_unique_key(&swap.to),
+            volume_usd.clone(),
+        );
+        Granularity::ALL.into_iter().for_each(|granularity| {
+            store.add(
+                0,
+                granularity
+                    .pool_snapshot(
+                        granularity.period_id(seconds),
+                        Some(granularity.volume_usd()),
+                    )
+                    .get_unique_key(&swap.to),
+                volume_usd.clone(),
+            );
+        });
+    });
+}
@@ -0,0 +1,66 @@
+use std::collections::BTreeSet;
+
+use substreams::key;
+use substreams::scalar::BigDecimal;
+use substreams::skip_empty_output;
+use substreams::store::{
+    DeltaBigDecimal, DeltaBigInt, DeltaExt, Deltas, StoreGet, StoreGetArray, StoreGetBigDecimal,
+    StoreGetBigInt, StoreGetProto, StoreNew, StoreSet, StoreSetBigDecimal,
+};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::Pool;
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_pool_tvl(
+    pool_balances_deltas: Deltas<DeltaBigInt>,
+    token_prices_deltas: Deltas<DeltaBigDecimal>,
+    pool_store: StoreGetProto<Pool>,
+    pool_balances_store: StoreGetBigInt,
+    token_prices_store: StoreGetBigDecimal,
+    token_pools_store: StoreGetArray<String>,
+    store: StoreSetBigDecimal,
+) {
+    skip_empty_output();
+
+    let mut pools: BTreeSet<String> = pool_balances_deltas
+        .iter()
+        .key_first_segment_eq(StoreKey::PoolBalance.unique_id())
+        .map(|delta| key::segment_at(&delta.key, 1).to_string())
+        .collect();
+
+    // A new price revalues every pool holding the token, even those whose balances
+    // didn't change.
+    token_prices_deltas
+        .iter()
+        .key_first_segment_eq(StoreKey::TokenPrice.unique_id())
+        .filter_map(|delta| {
+            token_pools_store
+                .get_last(StoreKey::TokenPools.get_unique_key(key::segment_at(&delta.key, 1)))
+        })
+        .for_each(|token_pools| pools.extend(token_pools));
+
+    for pool_address in pools {
+        let pool = match pool_store.get_last(StoreKey::Pool.get_unique_key(&pool_address)) {
+            Some(pool) => pool,
+            None => continue,
+        };
+
+        // Tokens without a price yet don't count towards the value locked.
+        let total_value_locked = [&pool.token_mint_a, &pool.token_mint_b]
+            .into_iter()
+            .filter_map(|token| {
+                utils::token_price_usd(token, &token_prices_store).map(|price| {
+                    utils::pool_balance_usd(&pool, token, &price, &pool_balances_store)
+                })
+            })
+            .fold(BigDecimal::zero(), |total, value| total + value);
+
+        store.set(
+            0,
+            StoreKey::PoolTotalValueLocked.get_unique_key(&pool_address),
+            &total_value_locked,
+        );
+    }
+}
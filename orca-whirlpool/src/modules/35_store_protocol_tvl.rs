@@ -0,0 +1,23 @@
+use substreams::skip_empty_output;
+use substreams::store::{
+    DeltaBigDecimal, DeltaExt, Deltas, StoreAdd, StoreAddBigDecimal, StoreNew,
+};
+
+use crate::key_store::StoreKey;
+
+#[substreams::handlers::store]
+pub fn store_protocol_tvl(pool_tvl_deltas: Deltas<DeltaBigDecimal>, store: StoreAddBigDecimal) {
+    skip_empty_output();
+
+    // The protocol value locked moves by the change of each pool's value locked.
+    pool_tvl_deltas
+        .iter()
+        .key_first_segment_eq(StoreKey::PoolTotalValueLocked.unique_id())
+        .for_each(|delta| {
+            store.add(
+                0,
+                StoreKey::ProtocolTotalValueLocked.unique_id(),
+                delta.new_value.clone() - delta.old_value.clone(),
+            );
+        });
+}
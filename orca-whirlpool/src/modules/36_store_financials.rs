@@ -0,0 +1,86 @@
+use substreams::log;
+use substreams::pb::substreams::Clock;
+use substreams::skip_empty_output;
+use substreams::store::{
+    DeltaInt64, Deltas, StoreAdd, StoreAddBigDecimal, StoreGet, StoreGetBigDecimal, StoreGetProto,
+    StoreNew,
+};
+
+use crate::key_store::{Granularity, StoreKey};
+use crate::pb::messari::orca_whirlpool::v1::{Pool, Swaps};
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_financials(
+    clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
+    pool_swaps: Swaps,
+    pool_store: StoreGetProto<Pool>,
+    token_prices_store: StoreGetBigDecimal,
+    store: StoreAddBigDecimal,
+) {
+    skip_empty_output();
+
+    let day_id = Granularity::Daily.period_id(clock.timestamp.unwrap().seconds);
+
+    // Delete the snapshot of the day rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, day_id| {
+        granularity.daily_snapshot(StoreKey::FinancialsDailySnapshot(day_id, None))
+    });
+
+    pool_swaps.data.iter().for_each(|swap| {
+        let pool = match pool_store.get_last(StoreKey::Pool.get_unique_key(&swap.to)) {
+            Some(pool) => pool,
+            None => {
+                log::info!("Pool not found: {:?}", swap.to);
+                return;
+            }
+        };
+
+        // Fees are charged on the input token of the swap.
+        [
+            (
+                StoreKey::VolumeUSD,
+                StoreKey::DailyVolumeUSD,
+                utils::swap_volume_usd(&pool, swap, &token_prices_store),
+            ),
+            (
+                StoreKey::TotalRevenue,
+                StoreKey::DailyTotalRevenue,
+                utils::amount_usd(&pool, &swap.token_in, &swap.fee_amount, &token_prices_store),
+            ),
+            (
+                StoreKey::SupplySideRevenue,
+                StoreKey::DailySupplySideRevenue,
+                utils::amount_usd(
+                    &pool,
+                    &swap.token_in,
+                    &swap.lp_fee_amount,
+                    &token_prices_store,
+                ),
+            ),
+            (
+                StoreKey::ProtocolSideRevenue,
+                StoreKey::DailyProtocolSideRevenue,
+                utils::amount_usd(
+                    &pool,
+                    &swap.token_in,
+                    &swap.protocol_fee_amount,
+                    &token_prices_store,
+                ),
+            ),
+        ]
+        .into_iter()
+        .for_each(|(cumulative_key, daily_key, amount_usd)| {
+            store.add_many(
+                0,
+                &vec![
+                    cumulative_key.unique_id(),
+                    StoreKey::FinancialsDailySnapshot(day_id, Some(Box::new(daily_key)))
+                        .unique_id(),
+                ],
+                amount_usd,
+            );
+        });
+    });
+}
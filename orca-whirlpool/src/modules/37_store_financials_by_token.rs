@@ -0,0 +1,64 @@
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::skip_empty_output;
+use substreams::store::{DeltaInt64, Deltas, StoreAdd, StoreAddBigInt, StoreNew};
+
+use crate::key_store::{Granularity, StoreKey};
+use crate::pb::messari::orca_whirlpool::v1::Swaps;
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_financials_by_token(
+    clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
+    pool_swaps: Swaps,
+    store: StoreAddBigInt,
+) {
+    skip_empty_output();
+
+    let day_id = Granularity::Daily.period_id(clock.timestamp.unwrap().seconds);
+
+    // Delete the snapshot of the day rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, day_id| {
+        granularity.daily_snapshot(StoreKey::FinancialsDailySnapshot(day_id, None))
+    });
+
+    pool_swaps.data.iter().for_each(|swap| {
+        [
+            (
+                StoreKey::DailyVolumeByTokenAmount,
+                &swap.token_in,
+                &swap.amount_in,
+            ),
+            (
+                StoreKey::DailyVolumeByTokenAmount,
+                &swap.token_out,
+                &swap.amount_out,
+            ),
+            (
+                StoreKey::DailyTotalRevenue,
+                &swap.token_in,
+                &swap.fee_amount,
+            ),
+            (
+                StoreKey::DailySupplySideRevenue,
+                &swap.token_in,
+                &swap.lp_fee_amount,
+            ),
+            (
+                StoreKey::DailyProtocolSideRevenue,
+                &swap.token_in,
+                &swap.protocol_fee_amount,
+            ),
+        ]
+        .into_iter()
+        .for_each(|(daily_key, token, amount)| {
+            store.add(
+                0,
+                StoreKey::FinancialsDailySnapshot(day_id, Some(Box::new(daily_key)))
+                    .get_unique_key(token),
+                BigInt::try_from(amount).unwrap_or_default().absolute(),
+            );
+        });
+    });
+}
@@ -0,0 +1,50 @@
+use std::collections::BTreeSet;
+
+use substreams::key;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::pb::substreams::Clock;
+use substreams::skip_empty_output;
+use substreams::store::{DeltaBigInt, DeltaExt, Deltas, StoreNew, StoreSet, StoreSetInt64};
+
+use crate::key_store::{Granularity, StoreKey};
+
+/// Stores the last day and block on which the balances or liquidity of each pool changed, so
+/// that `graph_out` can fill in the daily snapshots of the days the pool was idle.
+#[substreams::handlers::store]
+pub fn store_pool_snapshot_days(
+    clock: Clock,
+    pool_balances_deltas: Deltas<DeltaBigInt>,
+    pool_liquidity_deltas: Deltas<DeltaBigInt>,
+    store: StoreSetInt64,
+) {
+    skip_empty_output();
+
+    let day_id = Granularity::Daily.period_id(clock.timestamp.unwrap().seconds);
+
+    // Set each pool once, so that its deltas carry the day and block it was last active on.
+    let mut pools: BTreeSet<String> = BTreeSet::new();
+
+    [pool_balances_deltas, pool_liquidity_deltas]
+        .iter()
+        .for_each(|deltas| {
+            deltas
+                .iter()
+                .operation_not_eq(Operation::Delete)
+                .for_each(|delta| {
+                    pools.insert(key::segment_at(&delta.key, 1).to_string());
+                });
+        });
+
+    pools.into_iter().for_each(|pool_address| {
+        store.set(
+            0,
+            StoreKey::PoolSnapshotDay.get_unique_key(&pool_address),
+            &day_id,
+        );
+        store.set(
+            0,
+            StoreKey::PoolSnapshotBlock.get_unique_key(&pool_address),
+            &(clock.number as i64),
+        );
+    });
+}
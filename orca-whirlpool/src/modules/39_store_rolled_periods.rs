@@ -0,0 +1,22 @@
+use substreams::pb::substreams::Clock;
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSet, StoreSetInt64};
+
+use crate::key_store::Granularity;
+
+/// Records the day and hour of every processed block. The deltas of a new period carry
+/// the last period rolled over, which windowed stores evict.
+#[substreams::handlers::store]
+pub fn store_rolled_periods(clock: Clock, store: StoreSetInt64) {
+    skip_empty_output();
+
+    let seconds = clock.timestamp.unwrap().seconds;
+
+    Granularity::ALL.into_iter().for_each(|granularity| {
+        store.set(
+            0,
+            granularity.last_rolled_period().unique_id(),
+            &granularity.period_id(seconds),
+        );
+    });
+}
@@ -0,0 +1,88 @@
+use substreams::scalar::BigInt;
+use substreams::skip_empty_output;
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreDelete, StoreNew};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{Deposits, Positions, Withdraws};
+
+#[substreams::handlers::store]
+pub fn store_position_liquidity(
+    positions: Positions,
+    pool_deposits: Deposits,
+    pool_withdraws: Withdraws,
+    store: StoreAddBigInt,
+) {
+    skip_empty_output();
+
+    // Bundled position accounts are re-created when a bundle slot is reused, so a
+    // reopened position starts over from an empty history.
+    positions
+        .data
+        .iter()
+        .filter(|p| !p.is_closed)
+        .for_each(|position| {
+            [
+                StoreKey::PositionLiquidity,
+                StoreKey::PositionDepositCount,
+                StoreKey::PositionWithdrawCount,
+                StoreKey::PositionDepositedAmount,
+                StoreKey::PositionWithdrawnAmount,
+            ]
+            .into_iter()
+            .for_each(|field| store.delete_prefix(0, &field.get_unique_key(&position.address)));
+        });
+
+    pool_deposits.data.iter().for_each(|deposit| {
+        store.add(
+            0,
+            StoreKey::PositionLiquidity.get_unique_key(&deposit.position),
+            BigInt::try_from(&deposit.output_amount).unwrap_or_default(),
+        );
+        store.add(
+            0,
+            StoreKey::PositionDepositCount.get_unique_key(&deposit.position),
+            BigInt::one(),
+        );
+
+        [
+            (&deposit.token_a, &deposit.amount_a),
+            (&deposit.token_b, &deposit.amount_b),
+        ]
+        .into_iter()
+        .for_each(|(token, amount)| {
+            store.add(
+                0,
+                StoreKey::PositionDepositedAmount.get_unique_keys(&deposit.position, token),
+                BigInt::try_from(amount).unwrap_or_default(),
+            );
+        });
+    });
+
+    pool_withdraws.data.iter().for_each(|withdraw| {
+        store.add(
+            0,
+            StoreKey::PositionLiquidity.get_unique_key(&withdraw.position),
+            BigInt::try_from(&withdraw.output_amount)
+                .unwrap_or_default()
+                .neg(),
+        );
+        store.add(
+            0,
+            StoreKey::PositionWithdrawCount.get_unique_key(&withdraw.position),
+            BigInt::one(),
+        );
+
+        [
+            (&withdraw.token_a, &withdraw.amount_a),
+            (&withdraw.token_b, &withdraw.amount_b),
+        ]
+        .into_iter()
+        .for_each(|(token, amount)| {
+            store.add(
+                0,
+                StoreKey::PositionWithdrawnAmount.get_unique_keys(&withdraw.position, token),
+                BigInt::try_from(amount).unwrap_or_default(),
+            );
+        });
+    });
+}
@@ -0,0 +1,32 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSet, StoreSetString};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::Positions;
+
+#[substreams::handlers::store]
+pub fn store_position_mints(positions: Positions, store: StoreSetString) {
+    skip_empty_output();
+
+    // Bundled positions have no mint of their own; they move with their bundle, whose
+    // mint is keyed separately.
+    for position in positions
+        .data
+        .iter()
+        .filter(|p| !p.is_closed && !p.position_mint.is_empty())
+    {
+        store.set(
+            0,
+            StoreKey::PositionMint.get_unique_key(&position.position_mint),
+            &position.address,
+        );
+    }
+
+    for bundle in positions.bundles.iter() {
+        store.set(
+            0,
+            StoreKey::PositionBundleMint.get_unique_key(&bundle.position_bundle_mint),
+            &bundle.address,
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{
+    constants,
+    key_store::StoreKey,
+    pb::messari::orca_whirlpool::v1::{
+        event::Type, Event, Events, NftTransfer, PositionTransfer, PositionTransfers,
+    },
+    utils,
+};
+use substreams::{
+    skip_empty_output,
+    store::{StoreGet, StoreGetString},
+};
+
+#[substreams::handlers::map]
+pub fn map_position_transfers(
+    raw_events: Events,
+    position_mints_store: StoreGetString,
+    bundle_positions_store: StoreGetString,
+) -> Result<PositionTransfers, substreams::errors::Error> {
+    skip_empty_output();
+
+    let mut position_transfers: Vec<PositionTransfer> = Vec::new();
+
+    for event in raw_events.data {
+        if let Some(Type::NftTransfer(transfer)) = event.r#type.clone() {
+            if let Some(position) =
+                position_mints_store.get_last(StoreKey::PositionMint.get_unique_key(&transfer.mint))
+            {
+                position_transfers.push(position_transfer(&event, &transfer, position, 0));
+                continue;
+            }
+
+            let bundle = match position_mints_store
+                .get_last(StoreKey::PositionBundleMint.get_unique_key(&transfer.mint))
+            {
+                Some(bundle) => bundle,
+                None => continue,
+            };
+
+            // Every position open in the bundle moves with the bundle's NFT.
+            for bundle_index in 0..constants::POSITION_BUNDLE_SIZE {
+                let position = bundle_positions_store
+                    .get_last(
+                        StoreKey::BundlePosition
+                            .get_unique_keys(&bundle, &bundle_index.to_string()),
+                    )
+                    .unwrap_or_default();
+
+                if !position.is_empty() {
+                    position_transfers.push(position_transfer(
+                        &event,
+                        &transfer,
+                        position,
+                        bundle_index,
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(PositionTransfers {
+        data: position_transfers,
+    })
+}
+
+fn position_transfer(
+    event: &Event,
+    transfer: &NftTransfer,
+    position: String,
+    hop: u32,
+) -> PositionTransfer {
+    PositionTransfer {
+        id: utils::instruction_id("position-transfer", event, hop),
+        position,
+        position_mint: transfer.mint.clone(),
+        from: transfer.source_owner.clone(),
+        to: transfer.destination_owner.clone(),
+
+        slot: event.slot,
+        txn_id: event.txn_id.clone(),
+        block_height: event.block_height,
+        block_timestamp: event.block_timestamp,
+        block_hash: event.block_hash.clone(),
+    }
+}
@@ -0,0 +1,32 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSet, StoreSetString};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{PositionTransfers, Positions};
+
+#[substreams::handlers::store]
+pub fn store_position_owners(
+    positions: Positions,
+    position_transfers: PositionTransfers,
+    store: StoreSetString,
+) {
+    skip_empty_output();
+
+    for position in positions.data.iter().filter(|p| !p.is_closed) {
+        store.set(
+            0,
+            StoreKey::PositionOwner.get_unique_key(&position.address),
+            &position.owner,
+        );
+    }
+
+    // Whoever holds the position NFT, or the bundle NFT of a bundled position, controls
+    // the position, whatever the authority signing its instructions.
+    for transfer in position_transfers.data.iter() {
+        store.set(
+            0,
+            StoreKey::PositionOwner.get_unique_key(&transfer.position),
+            &transfer.to,
+        );
+    }
+}
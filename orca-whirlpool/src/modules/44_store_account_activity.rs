@@ -0,0 +1,67 @@
+use substreams::scalar::BigInt;
+use substreams::skip_empty_output;
+use substreams::store::{StoreAdd, StoreAddBigInt, StoreGet, StoreGetString, StoreNew};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::{Deposits, Swaps, Withdraws};
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_account_activity(
+    pool_deposits: Deposits,
+    pool_withdraws: Withdraws,
+    pool_swaps: Swaps,
+    position_owners_store: StoreGetString,
+    store: StoreAddBigInt,
+) {
+    skip_empty_output();
+
+    // Accounts are resolved the same way as in `store_unique_users`, so that every
+    // account counted here has been seen there.
+    pool_deposits.data.iter().for_each(|deposit| {
+        let account = utils::position_owner(
+            &deposit.position,
+            deposit.from.clone(),
+            &position_owners_store,
+        );
+        store.add(
+            0,
+            StoreKey::AccountDepositCount.get_unique_key(&account),
+            BigInt::one(),
+        );
+    });
+
+    pool_withdraws.data.iter().for_each(|withdraw| {
+        let account = utils::position_owner(
+            &withdraw.position,
+            withdraw.from.clone(),
+            &position_owners_store,
+        );
+        store.add(
+            0,
+            StoreKey::AccountWithdrawCount.get_unique_key(&account),
+            BigInt::one(),
+        );
+    });
+
+    pool_swaps.data.iter().for_each(|swap| {
+        store.add(
+            0,
+            StoreKey::AccountSwapCount.get_unique_key(&swap.from),
+            BigInt::one(),
+        );
+
+        [
+            (&swap.token_in, &swap.amount_in),
+            (&swap.token_out, &swap.amount_out),
+        ]
+        .into_iter()
+        .for_each(|(token, amount)| {
+            store.add(
+                0,
+                StoreKey::AccountVolumeByTokenAmount.get_unique_keys(&swap.from, token),
+                BigInt::try_from(amount).unwrap_or_default().absolute(),
+            );
+        });
+    });
+}
@@ -0,0 +1,69 @@
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::skip_empty_output;
+use substreams::store::{
+    DeltaInt64, Deltas, StoreGet, StoreGetString, StoreNew, StoreSetIfNotExists,
+    StoreSetIfNotExistsBigInt,
+};
+
+use crate::key_store::{Granularity, StoreKey};
+use crate::pb::messari::orca_whirlpool::v1::{Deposits, Swaps, Withdraws};
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_pool_users(
+    clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
+    pool_deposits: Deposits,
+    pool_withdraws: Withdraws,
+    pool_swaps: Swaps,
+    position_owners_store: StoreGetString,
+    store: StoreSetIfNotExistsBigInt,
+) {
+    skip_empty_output();
+
+    let bigint1 = BigInt::one();
+    let seconds = clock.timestamp.unwrap().seconds;
+
+    // Delete the snapshots of the days and hours rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, period_id| {
+        Some(granularity.pool_snapshot(period_id, None))
+    });
+
+    // Users are resolved the same way as in `store_unique_users`.
+    let set_pool_user = |pool: &str, user: &str| {
+        let mut keys = vec![StoreKey::PoolUser.get_unique_keys(pool, user)];
+        keys.extend(Granularity::ALL.into_iter().map(|granularity| {
+            granularity
+                .pool_snapshot(granularity.period_id(seconds), Some(StoreKey::ActiveUsers))
+                .get_unique_keys(pool, user)
+        }));
+
+        store.set_if_not_exists_many(0, &keys, &bigint1);
+    };
+
+    pool_deposits.data.iter().for_each(|deposit| {
+        set_pool_user(
+            &deposit.to,
+            &utils::position_owner(
+                &deposit.position,
+                deposit.from.clone(),
+                &position_owners_store,
+            ),
+        );
+    });
+    pool_withdraws.data.iter().for_each(|withdraw| {
+        set_pool_user(
+            &withdraw.to,
+            &utils::position_owner(
+                &withdraw.position,
+                withdraw.from.clone(),
+                &position_owners_store,
+            ),
+        );
+    });
+    pool_swaps
+        .data
+        .iter()
+        .for_each(|swap| set_pool_user(&swap.to, &swap.from));
+}
@@ -0,0 +1,91 @@
+use substreams::key;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::pb::substreams::Clock;
+use substreams::scalar::BigInt;
+use substreams::skip_empty_output;
+use substreams::store::{
+    DeltaBigInt, DeltaExt, DeltaInt64, Deltas, StoreAdd, StoreAddBigInt, StoreNew,
+};
+
+use crate::key_store::{Granularity, StoreKey};
+use crate::pb::messari::orca_whirlpool::v1::{Deposits, Swaps, Withdraws};
+use crate::utils;
+
+#[substreams::handlers::store]
+pub fn store_pool_activity(
+    clock: Clock,
+    rolled_periods_deltas: Deltas<DeltaInt64>,
+    pool_deposits: Deposits,
+    pool_withdraws: Withdraws,
+    pool_swaps: Swaps,
+    pool_users_deltas: Deltas<DeltaBigInt>,
+    store: StoreAddBigInt,
+) {
+    skip_empty_output();
+
+    let bigint1 = BigInt::one();
+    let seconds = clock.timestamp.unwrap().seconds;
+
+    // Delete the snapshots of the days and hours rolled over
+    utils::delete_rolled_snapshots(&store, &rolled_periods_deltas, |granularity, period_id| {
+        Some(granularity.pool_snapshot(period_id, None))
+    });
+
+    // Update the cumulative counts of the pool, its counts for the day and the hour, and
+    // their total transaction counts.
+    let update_counts = |pool: &str, count_type: StoreKey| {
+        let mut keys = vec![
+            count_type.get_unique_key(pool),
+            StoreKey::TxnCount.get_unique_key(pool),
+        ];
+        keys.extend(Granularity::ALL.into_iter().flat_map(|granularity| {
+            let period_id = granularity.period_id(seconds);
+
+            [count_type.clone(), StoreKey::TxnCount].map(|field| {
+                granularity
+                    .pool_snapshot(period_id, Some(field))
+                    .get_unique_key(pool)
+            })
+        }));
+
+        store.add_many(0, &keys, &bigint1);
+    };
+
+    pool_deposits
+        .data
+        .iter()
+        .for_each(|deposit| update_counts(&deposit.to, StoreKey::DepositCount));
+    pool_withdraws
+        .data
+        .iter()
+        .for_each(|withdraw| update_counts(&withdraw.to, StoreKey::WithdrawCount));
+    pool_swaps
+        .data
+        .iter()
+        .for_each(|swap| update_counts(&swap.to, StoreKey::SwapCount));
+
+    // Each user is set once per pool and once per pool and day, so every created key
+    // counts towards the users of its pool.
+    pool_users_deltas
+        .iter()
+        .key_first_segment_eq(StoreKey::PoolUser.unique_id())
+        .operation_eq(Operation::Create)
+        .for_each(|delta| {
+            let pool = key::segment_at(&delta.key, 1);
+            store.add(0, StoreKey::CumulativeUsers.get_unique_key(pool), &bigint1);
+        });
+
+    Granularity::ALL.into_iter().for_each(|granularity| {
+        let active_users =
+            granularity.pool_snapshot(granularity.period_id(seconds), Some(StoreKey::ActiveUsers));
+
+        pool_users_deltas
+            .iter()
+            .operation_eq(Operation::Create)
+            .filter(|delta| delta.key.starts_with(&active_users.unique_id()))
+            .for_each(|delta| {
+                let pool = key::segment_at(&delta.key, 3);
+                store.add(0, active_users.get_unique_key(pool), &bigint1);
+            });
+    });
+}
@@ -0,0 +1,22 @@
+use substreams::skip_empty_output;
+use substreams::store::{Appender, StoreAppend};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::Pools;
+
+/// Indexes the pools holding each token, so that `store_pool_tvl` can revalue them when
+/// the price of the token changes.
+#[substreams::handlers::store]
+pub fn store_token_pools(initialized_pools: Pools, store: StoreAppend<String>) {
+    skip_empty_output();
+
+    for pool in initialized_pools.data {
+        for token in [&pool.token_mint_a, &pool.token_mint_b] {
+            store.append(
+                0,
+                StoreKey::TokenPools.get_unique_key(token),
+                pool.address.clone(),
+            );
+        }
+    }
+}
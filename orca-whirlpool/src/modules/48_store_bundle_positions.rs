@@ -0,0 +1,31 @@
+use substreams::skip_empty_output;
+use substreams::store::{StoreNew, StoreSet, StoreSetString};
+
+use crate::key_store::StoreKey;
+use crate::pb::messari::orca_whirlpool::v1::Positions;
+
+/// Maps the slots of each position bundle to the position open in them, so that
+/// `map_position_transfers` can move the bundled positions along with the bundle's NFT.
+#[substreams::handlers::store]
+pub fn store_bundle_positions(positions: Positions, store: StoreSetString) {
+    skip_empty_output();
+
+    for position in positions.data.iter() {
+        let (bundle, bundle_index) = match (&position.position_bundle, position.bundle_index) {
+            (Some(bundle), Some(bundle_index)) => (bundle, bundle_index),
+            _ => continue,
+        };
+
+        // Closed slots are emptied, as they can be reused by another position.
+        let value = match position.is_closed {
+            true => String::new(),
+            false => position.address.clone(),
+        };
+
+        store.set(
+            0,
+            StoreKey::BundlePosition.get_unique_keys(bundle, &bundle_index.to_string()),
+            &value,
+        );
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
     key_store::StoreKey,
-    pb::messari::orca_whirlpool::v1::{event::Type, Deposit, Deposits, Event, Events, Pool},
+    pb::messari::orca_whirlpool::v1::{
+        anchor_event, event::Type, Deposit, Deposits, Event, Events, LiquidityChanged, Pool,
+    },
     traits::deposit_instructions::DepositInstruction,
+    utils,
 };
 use substreams::{
     log, skip_empty_output,
@@ -48,8 +51,28 @@ fn process_deposit<T: DepositInstruction>(
         }
     };
 
+    // Transfer fees are taken from the event log, falling back to those withheld on the
+    // vault transfers.
+    let (transfer_fee_a, transfer_fee_b) = liquidity_increased_event(event)
+        .map(|changed| {
+            (
+                changed.token_a_transfer_fee.clone(),
+                changed.token_b_transfer_fee.clone(),
+            )
+        })
+        .unwrap_or_else(|| {
+            (
+                deposit_event.transfer_fee_a(),
+                deposit_event.transfer_fee_b(),
+            )
+        });
+    let (amount_a, net_amount_a) =
+        utils::transfer_amounts(&deposit_event.amount_a(), &transfer_fee_a);
+    let (amount_b, net_amount_b) =
+        utils::transfer_amounts(&deposit_event.amount_b(), &transfer_fee_b);
+
     deposits.push(Deposit {
-        id: format!("DEPOSIT-{}-{}", event.txn_id, event.slot),
+        id: utils::instruction_id("DEPOSIT", event, 0),
 
         token_a: pool.token_mint_a,
         token_b: pool.token_mint_b,
@@ -57,14 +80,22 @@ fn process_deposit<T: DepositInstruction>(
         token_a_balance: deposit_event.amount_a_post(),
         token_b_balance: deposit_event.amount_b_post(),
 
-        amount_a: deposit_event.amount_a(),
-        amount_b: deposit_event.amount_b(),
+        amount_a,
+        amount_b,
 
         output_amount: deposit_event.liquidity_amount(),
 
         from: deposit_event.position_authority(),
         to: deposit_event.whirlpool(),
 
+        position: deposit_event.position(),
+        position_token_account: deposit_event.position_token_account(),
+
+        transfer_fee_a,
+        transfer_fee_b,
+        net_amount_a,
+        net_amount_b,
+
         slot: event.slot,
         txn_id: event.txn_id.clone(),
         block_height: event.block_height,
@@ -72,3 +103,14 @@ fn process_deposit<T: DepositInstruction>(
         block_hash: event.block_hash.clone(),
     })
 }
+
+/// The `LiquidityIncreased` event logged by the instruction, if any.
+fn liquidity_increased_event(event: &Event) -> Option<&LiquidityChanged> {
+    event
+        .anchor_events
+        .iter()
+        .find_map(|anchor_event| match &anchor_event.r#type {
+            Some(anchor_event::Type::LiquidityIncreased(changed)) => Some(changed),
+            _ => None,
+        })
+}
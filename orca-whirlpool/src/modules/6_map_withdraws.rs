@@ -1,7 +1,10 @@
 use crate::{
     key_store::StoreKey,
-    pb::messari::orca_whirlpool::v1::{event::Type, Event, Events, Pool, Withdraw, Withdraws},
+    pb::messari::orca_whirlpool::v1::{
+        anchor_event, event::Type, Event, Events, LiquidityChanged, Pool, Withdraw, Withdraws,
+    },
     traits::withdraw_instructions::WithdrawInstruction,
+    utils,
 };
 use substreams::{
     log, skip_empty_output,
@@ -48,8 +51,28 @@ fn process_withdraw<T: WithdrawInstruction>(
         }
     };
 
+    // Transfer fees are taken from the event log, falling back to those withheld on the
+    // vault transfers.
+    let (transfer_fee_a, transfer_fee_b) = liquidity_decreased_event(event)
+        .map(|changed| {
+            (
+                changed.token_a_transfer_fee.clone(),
+                changed.token_b_transfer_fee.clone(),
+            )
+        })
+        .unwrap_or_else(|| {
+            (
+                withdraw_event.transfer_fee_a(),
+                withdraw_event.transfer_fee_b(),
+            )
+        });
+    let (amount_a, net_amount_a) =
+        utils::transfer_amounts(&withdraw_event.amount_a(), &transfer_fee_a);
+    let (amount_b, net_amount_b) =
+        utils::transfer_amounts(&withdraw_event.amount_b(), &transfer_fee_b);
+
     withdraws.push(Withdraw {
-        id: format!("WITHDRAW-{}-{}", event.txn_id.clone(), event.slot),
+        id: utils::instruction_id("WITHDRAW", event, 0),
 
         token_a: pool.token_mint_a,
         token_b: pool.token_mint_b,
@@ -57,14 +80,22 @@ fn process_withdraw<T: WithdrawInstruction>(
         token_a_balance: withdraw_event.amount_a_post(),
         token_b_balance: withdraw_event.amount_b_post(),
 
-        amount_a: withdraw_event.amount_a(),
-        amount_b: withdraw_event.amount_b(),
+        amount_a,
+        amount_b,
 
         output_amount: withdraw_event.liquidity_amount(),
 
         from: withdraw_event.position_authority(),
         to: withdraw_event.whirlpool(),
 
+        position: withdraw_event.position(),
+        position_token_account: withdraw_event.position_token_account(),
+
+        transfer_fee_a,
+        transfer_fee_b,
+        net_amount_a,
+        net_amount_b,
+
         slot: event.slot,
         txn_id: event.txn_id.clone(),
         block_height: event.block_height,
@@ -72,3 +103,14 @@ fn process_withdraw<T: WithdrawInstruction>(
         block_hash: event.block_hash.clone(),
     });
 }
+
+/// The `LiquidityDecreased` event logged by the instruction, if any.
+fn liquidity_decreased_event(event: &Event) -> Option<&LiquidityChanged> {
+    event
+        .anchor_events
+        .iter()
+        .find_map(|anchor_event| match &anchor_event.r#type {
+            Some(anchor_event::Type::LiquidityDecreased(changed)) => Some(changed),
+            _ => None,
+        })
+}
@@ -1,16 +1,21 @@
 use crate::key_store::StoreKey;
-use crate::pb::messari::orca_whirlpool::v1::{event::Type, Event, Events, Pool, Swap, Swaps};
+use crate::pb::messari::orca_whirlpool::v1::{
+    anchor_event, event::Type, Event, Events, Pool, Swap, Swaps, Traded,
+};
 use crate::traits::swap_instructions::SwapInstruction;
+use crate::utils;
 use substreams::log;
 use substreams::{
+    scalar::BigInt,
     skip_empty_output,
-    store::{StoreGet, StoreGetProto},
+    store::{StoreGet, StoreGetInt64, StoreGetProto},
 };
 
 #[substreams::handlers::map]
 pub fn map_swaps(
     raw_events: Events,
     pool_store: StoreGetProto<Pool>,
+    pool_fee_rates_store: StoreGetInt64,
 ) -> Result<Swaps, substreams::errors::Error> {
     skip_empty_output();
 
@@ -20,16 +25,40 @@ pub fn map_swaps(
         if let Some(event_type) = event.r#type.clone() {
             match event_type {
                 Type::TwoHopSwap(two_hop_swap_event) => {
-                    process_swap(&two_hop_swap_event, &pool_store, &event, &mut swaps);
+                    process_swap(
+                        &two_hop_swap_event,
+                        &pool_store,
+                        &pool_fee_rates_store,
+                        &event,
+                        &mut swaps,
+                    );
                 }
                 Type::TwoHopSwapV2(two_hop_swap_v2_event) => {
-                    process_swap(&two_hop_swap_v2_event, &pool_store, &event, &mut swaps);
+                    process_swap(
+                        &two_hop_swap_v2_event,
+                        &pool_store,
+                        &pool_fee_rates_store,
+                        &event,
+                        &mut swaps,
+                    );
                 }
                 Type::Swap(orca_swap_event) => {
-                    process_swap(&orca_swap_event, &pool_store, &event, &mut swaps);
+                    process_swap(
+                        &orca_swap_event,
+                        &pool_store,
+                        &pool_fee_rates_store,
+                        &event,
+                        &mut swaps,
+                    );
                 }
                 Type::SwapV2(orca_swap_v2_event) => {
-                    process_swap(&orca_swap_v2_event, &pool_store, &event, &mut swaps);
+                    process_swap(
+                        &orca_swap_v2_event,
+                        &pool_store,
+                        &pool_fee_rates_store,
+                        &event,
+                        &mut swaps,
+                    );
                 }
                 _ => {}
             }
@@ -42,22 +71,13 @@ pub fn map_swaps(
 fn process_swap<T: SwapInstruction>(
     swap_event: &T,
     pool_store: &StoreGetProto<Pool>,
+    pool_fee_rates_store: &StoreGetInt64,
     event: &Event,
     swaps: &mut Vec<Swap>,
 ) {
     log::info!("Processing swap: {:?}", event.txn_id);
 
-    let swap = handle_swap(
-        swap_event.a_to_b(),
-        swap_event.amount_a(),
-        swap_event.amount_b(),
-        swap_event.amount_a_post(),
-        swap_event.amount_b_post(),
-        swap_event.token_authority(),
-        swap_event.whirlpool(),
-        pool_store,
-        event.clone(),
-    );
+    let swap = handle_swap(swap_event, pool_store, pool_fee_rates_store, event, 0);
 
     if let Some(swap) = swap {
         swaps.push(swap);
@@ -66,15 +86,11 @@ fn process_swap<T: SwapInstruction>(
     if swap_event.is_two_hop() {
         if let Some(second_hop) = swap_event.second_hop() {
             let second_swap = handle_swap(
-                second_hop.a_to_b(),
-                second_hop.amount_a(),
-                second_hop.amount_b(),
-                second_hop.amount_a_post(),
-                second_hop.amount_b_post(),
-                second_hop.token_authority(),
-                second_hop.whirlpool(),
+                second_hop.as_ref(),
                 pool_store,
-                event.clone(),
+                pool_fee_rates_store,
+                event,
+                1,
             );
 
             if let Some(second_swap) = second_swap {
@@ -84,17 +100,19 @@ fn process_swap<T: SwapInstruction>(
     }
 }
 
+/// Builds the swap of a single hop of `swap_event`, the `hop`-th leg of its instruction.
 fn handle_swap(
-    a_to_b: bool,
-    amount_a: String,
-    amount_b: String,
-    token_a_balance: String,
-    token_b_balance: String,
-    signer: String,
-    pool_address: String,
+    swap_event: &dyn SwapInstruction,
     pool_store: &StoreGetProto<Pool>,
-    event: Event,
+    pool_fee_rates_store: &StoreGetInt64,
+    event: &Event,
+    hop: u32,
 ) -> Option<Swap> {
+    let a_to_b = swap_event.a_to_b();
+    let pool_address = swap_event.whirlpool();
+    let token_a_balance = swap_event.amount_a_post();
+    let token_b_balance = swap_event.amount_b_post();
+
     let pool = match pool_store.get_last(StoreKey::Pool.get_unique_key(&pool_address)) {
         Some(pool) => pool,
         None => {
@@ -103,29 +121,84 @@ fn handle_swap(
         }
     };
 
+    // The pool price after the swap, approximated from the vault balances when the
+    // program did not log it. It is left unset when the decimals of a mint are unknown.
+    let traded = traded_event(event, hop).filter(|traded| traded.whirlpool == pool_address);
+    let price = traded
+        .map(|traded| (traded.post_sqrt_price.clone(), false))
+        .or_else(|| {
+            utils::balances_to_sqrt_price_x64(&token_a_balance, &token_b_balance)
+                .map(|sqrt_price_x64| (sqrt_price_x64, true))
+        })
+        .zip(pool.decimals_a.zip(pool.decimals_b))
+        .map(
+            |((sqrt_price_x64, approximated), (decimals_a, decimals_b))| {
+                utils::pool_price(&sqrt_price_x64, decimals_a, decimals_b, approximated)
+            },
+        );
+
     let (token_in, amount_in, token_in_balance, token_out, amount_out, token_out_balance) =
         if a_to_b {
             (
                 pool.token_mint_a,
-                amount_a,
+                swap_event.amount_a(),
                 token_a_balance,
                 pool.token_mint_b,
-                amount_b,
+                swap_event.amount_b(),
                 token_b_balance,
             )
         } else {
             (
                 pool.token_mint_b,
-                amount_b,
+                swap_event.amount_b(),
                 token_b_balance,
                 pool.token_mint_a,
-                amount_a,
+                swap_event.amount_a(),
                 token_a_balance,
             )
         };
 
+    let fee_rate = pool_fee_rates_store
+        .get_last(StoreKey::PoolFeeRate.get_unique_key(&pool_address))
+        .unwrap_or_default();
+    let protocol_fee_rate = pool_fee_rates_store
+        .get_last(StoreKey::PoolProtocolFeeRate.get_unique_key(&pool_address))
+        .unwrap_or_default();
+
+    // Fees are charged on the input token, on top of the amount that is swapped. The
+    // `Traded` event log reports them exactly; otherwise derive them from the fee rates.
+    let (fee_amount, lp_fee_amount, protocol_fee_amount) = match traded {
+        Some(traded) => {
+            let lp_fee_amount = BigInt::try_from(&traded.lp_fee).unwrap_or_default();
+            let protocol_fee_amount = BigInt::try_from(&traded.protocol_fee).unwrap_or_default();
+            (
+                lp_fee_amount.clone() + protocol_fee_amount.clone(),
+                lp_fee_amount,
+                protocol_fee_amount,
+            )
+        }
+        None => utils::swap_fees(
+            &BigInt::try_from(&amount_in).unwrap_or_default().absolute(),
+            fee_rate,
+            protocol_fee_rate,
+        ),
+    };
+
+    // Transfer fees are taken from the `Traded` event log, falling back to those withheld
+    // on the vault transfers.
+    let (transfer_fee_in, transfer_fee_out) = match traded {
+        Some(traded) => (
+            traded.input_transfer_fee.clone(),
+            traded.output_transfer_fee.clone(),
+        ),
+        None if a_to_b => (swap_event.transfer_fee_a(), swap_event.transfer_fee_b()),
+        None => (swap_event.transfer_fee_b(), swap_event.transfer_fee_a()),
+    };
+    let (amount_in, net_amount_in) = utils::transfer_amounts(&amount_in, &transfer_fee_in);
+    let (amount_out, net_amount_out) = utils::transfer_amounts(&amount_out, &transfer_fee_out);
+
     Some(Swap {
-        id: format!("SWAP-{}-{}", event.txn_id, event.slot),
+        id: utils::instruction_id("SWAP", event, hop),
 
         token_in,
         token_out,
@@ -136,13 +209,38 @@ fn handle_swap(
         amount_in,
         amount_out,
 
-        from: signer,
+        from: swap_event.token_authority(),
         to: pool_address,
 
+        fee_amount: fee_amount.to_string(),
+        lp_fee_amount: lp_fee_amount.to_string(),
+        protocol_fee_amount: protocol_fee_amount.to_string(),
+
+        pre_sqrt_price: traded.map(|traded| traded.pre_sqrt_price.clone()),
+        post_sqrt_price: traded.map(|traded| traded.post_sqrt_price.clone()),
+        price,
+
+        transfer_fee_in,
+        transfer_fee_out,
+        net_amount_in,
+        net_amount_out,
+
         slot: event.slot,
-        txn_id: event.txn_id,
+        txn_id: event.txn_id.clone(),
         block_height: event.block_height,
         block_timestamp: event.block_timestamp,
-        block_hash: event.block_hash,
+        block_hash: event.block_hash.clone(),
     })
 }
+
+/// The `Traded` event logged for the `hop`-th leg of the swap instruction, if any.
+fn traded_event(event: &Event, hop: u32) -> Option<&Traded> {
+    event
+        .anchor_events
+        .iter()
+        .filter_map(|anchor_event| match &anchor_event.r#type {
+            Some(anchor_event::Type::Traded(traded)) => Some(traded),
+            _ => None,
+        })
+        .nth(hop as usize)
+}
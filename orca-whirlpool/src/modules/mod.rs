@@ -25,6 +25,36 @@ pub mod map_swaps;
 #[path = "9_store_swaps.rs"]
 pub mod store_swaps;
 
+#[path = "10_map_positions.rs"]
+pub mod map_positions;
+
+#[path = "11_store_positions.rs"]
+pub mod store_positions;
+
+#[path = "12_map_fee_collections.rs"]
+pub mod map_fee_collections;
+
+#[path = "13_map_rewards.rs"]
+pub mod map_rewards;
+
+#[path = "14_store_reward_tokens.rs"]
+pub mod store_reward_tokens;
+
+#[path = "15_store_fee_tiers.rs"]
+pub mod store_fee_tiers;
+
+#[path = "16_store_pool_fee_rates.rs"]
+pub mod store_pool_fee_rates;
+
+#[path = "17_map_configs.rs"]
+pub mod map_configs;
+
+#[path = "18_store_configs.rs"]
+pub mod store_configs;
+
+#[path = "19_store_pool_prices.rs"]
+pub mod store_pool_prices;
+
 #[path = "20_store_unique_users.rs"]
 pub mod store_unique_users;
 
@@ -46,5 +76,71 @@ pub mod store_activity_counts;
 #[path = "26_store_volume_by_token_amount.rs"]
 pub mod store_volume_by_token_amount;
 
+#[path = "27_store_collected_fees.rs"]
+pub mod store_collected_fees;
+
+#[path = "28_store_collected_rewards.rs"]
+pub mod store_collected_rewards;
+
+#[path = "29_store_swap_fees.rs"]
+pub mod store_swap_fees;
+
+#[path = "30_store_tokens.rs"]
+pub mod store_tokens;
+
+#[path = "31_store_sol_price.rs"]
+pub mod store_sol_price;
+
+#[path = "32_store_token_prices.rs"]
+pub mod store_token_prices;
+
+#[path = "33_store_volume_usd.rs"]
+pub mod store_volume_usd;
+
+#[path = "34_store_pool_tvl.rs"]
+pub mod store_pool_tvl;
+
+#[path = "35_store_protocol_tvl.rs"]
+pub mod store_protocol_tvl;
+
+#[path = "36_store_financials.rs"]
+pub mod store_financials;
+
+#[path = "37_store_financials_by_token.rs"]
+pub mod store_financials_by_token;
+
+#[path = "38_store_pool_snapshot_days.rs"]
+pub mod store_pool_snapshot_days;
+
+#[path = "39_store_rolled_periods.rs"]
+pub mod store_rolled_periods;
+
+#[path = "40_store_position_liquidity.rs"]
+pub mod store_position_liquidity;
+
+#[path = "41_store_position_mints.rs"]
+pub mod store_position_mints;
+
+#[path = "42_map_position_transfers.rs"]
+pub mod map_position_transfers;
+
+#[path = "43_store_position_owners.rs"]
+pub mod store_position_owners;
+
+#[path = "44_store_account_activity.rs"]
+pub mod store_account_activity;
+
+#[path = "45_store_pool_users.rs"]
+pub mod store_pool_users;
+
+#[path = "46_store_pool_activity.rs"]
+pub mod store_pool_activity;
+
+#[path = "47_store_token_pools.rs"]
+pub mod store_token_pools;
+
+#[path = "48_store_bundle_positions.rs"]
+pub mod store_bundle_positions;
+
 #[path = "100_graph_out.rs"]
 pub mod graph_out;
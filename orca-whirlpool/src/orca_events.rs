@@ -0,0 +1,155 @@
+use crate::constants::{self, EventDiscriminatorConstants};
+use crate::pb::messari::orca_whirlpool::v1::{
+    anchor_event, AnchorEvent, LiquidityChanged, PoolInitialized, Traded,
+};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use substreams_solana::block_view::InstructionView;
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct PoolInitializedEvent {
+    pub whirlpool: [u8; 32],
+    pub whirlpools_config: [u8; 32],
+    pub token_mint_a: [u8; 32],
+    pub token_mint_b: [u8; 32],
+    pub tick_spacing: u16,
+    pub token_program_a: [u8; 32],
+    pub token_program_b: [u8; 32],
+    pub decimals_a: u8,
+    pub decimals_b: u8,
+    pub initial_sqrt_price: u128,
+}
+
+// Shared by the `LiquidityIncreased` and `LiquidityDecreased` events.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct LiquidityChangedEvent {
+    pub whirlpool: [u8; 32],
+    pub position: [u8; 32],
+    pub tick_lower_index: i32,
+    pub tick_upper_index: i32,
+    pub liquidity: u128,
+    pub token_a_amount: u64,
+    pub token_b_amount: u64,
+    pub token_a_transfer_fee: u64,
+    pub token_b_transfer_fee: u64,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+pub struct TradedEvent {
+    pub whirlpool: [u8; 32],
+    pub a_to_b: bool,
+    pub pre_sqrt_price: u128,
+    pub post_sqrt_price: u128,
+    pub input_amount: u64,
+    pub output_amount: u64,
+    pub input_transfer_fee: u64,
+    pub output_transfer_fee: u64,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+}
+
+/// Events the Whirlpool program logs by invoking itself through Anchor's `emit_cpi!`.
+#[derive(Debug)]
+pub enum OrcaEvents {
+    PoolInitialized(PoolInitializedEvent),
+    LiquidityIncreased(LiquidityChangedEvent),
+    LiquidityDecreased(LiquidityChangedEvent),
+    Traded(TradedEvent),
+}
+
+impl OrcaEvents {
+    /// Whether `instruction_view` is an event self-CPI rather than a Whirlpool instruction.
+    pub fn is_event(instruction_view: &InstructionView) -> bool {
+        instruction_view.program_id() == constants::ORCA_WHIRLPOOL
+            && instruction_view
+                .data()
+                .starts_with(&EventDiscriminatorConstants::EVENT_IX_TAG)
+    }
+
+    pub fn from(instruction_view: &InstructionView) -> Option<Self> {
+        if !Self::is_event(instruction_view) {
+            return None;
+        }
+
+        let data = &instruction_view.data()[8..];
+        if data.len() < 8 {
+            return None;
+        }
+        let (tag, mut rest) = data.split_at(8);
+
+        match tag {
+            x if x == EventDiscriminatorConstants::POOL_INITIALIZED => Some(
+                OrcaEvents::PoolInitialized(PoolInitializedEvent::deserialize(&mut rest).ok()?),
+            ),
+            x if x == EventDiscriminatorConstants::LIQUIDITY_INCREASED => Some(
+                OrcaEvents::LiquidityIncreased(LiquidityChangedEvent::deserialize(&mut rest).ok()?),
+            ),
+            x if x == EventDiscriminatorConstants::LIQUIDITY_DECREASED => Some(
+                OrcaEvents::LiquidityDecreased(LiquidityChangedEvent::deserialize(&mut rest).ok()?),
+            ),
+            x if x == EventDiscriminatorConstants::TRADED => Some(OrcaEvents::Traded(
+                TradedEvent::deserialize(&mut rest).ok()?,
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl From<OrcaEvents> for AnchorEvent {
+    fn from(event: OrcaEvents) -> Self {
+        let r#type = match event {
+            OrcaEvents::PoolInitialized(data) => {
+                anchor_event::Type::PoolInitialized(PoolInitialized {
+                    whirlpool: bs58::encode(data.whirlpool).into_string(),
+                    whirlpools_config: bs58::encode(data.whirlpools_config).into_string(),
+                    token_mint_a: bs58::encode(data.token_mint_a).into_string(),
+                    token_mint_b: bs58::encode(data.token_mint_b).into_string(),
+                    tick_spacing: data.tick_spacing as u32,
+                    token_program_a: bs58::encode(data.token_program_a).into_string(),
+                    token_program_b: bs58::encode(data.token_program_b).into_string(),
+                    decimals_a: data.decimals_a as u32,
+                    decimals_b: data.decimals_b as u32,
+                    initial_sqrt_price: data.initial_sqrt_price.to_string(),
+                })
+            }
+            OrcaEvents::LiquidityIncreased(data) => {
+                anchor_event::Type::LiquidityIncreased(data.into())
+            }
+            OrcaEvents::LiquidityDecreased(data) => {
+                anchor_event::Type::LiquidityDecreased(data.into())
+            }
+            OrcaEvents::Traded(data) => anchor_event::Type::Traded(Traded {
+                whirlpool: bs58::encode(data.whirlpool).into_string(),
+                a_to_b: data.a_to_b,
+                pre_sqrt_price: data.pre_sqrt_price.to_string(),
+                post_sqrt_price: data.post_sqrt_price.to_string(),
+                input_amount: data.input_amount.to_string(),
+                output_amount: data.output_amount.to_string(),
+                input_transfer_fee: data.input_transfer_fee.to_string(),
+                output_transfer_fee: data.output_transfer_fee.to_string(),
+                lp_fee: data.lp_fee.to_string(),
+                protocol_fee: data.protocol_fee.to_string(),
+            }),
+        };
+
+        AnchorEvent {
+            r#type: Some(r#type),
+        }
+    }
+}
+
+impl From<LiquidityChangedEvent> for LiquidityChanged {
+    fn from(data: LiquidityChangedEvent) -> Self {
+        LiquidityChanged {
+            whirlpool: bs58::encode(data.whirlpool).into_string(),
+            position: bs58::encode(data.position).into_string(),
+            tick_lower_index: data.tick_lower_index,
+            tick_upper_index: data.tick_upper_index,
+            liquidity: data.liquidity.to_string(),
+            token_a_amount: data.token_a_amount.to_string(),
+            token_b_amount: data.token_b_amount.to_string(),
+            token_a_transfer_fee: data.token_a_transfer_fee.to_string(),
+            token_b_transfer_fee: data.token_b_transfer_fee.to_string(),
+        }
+    }
+}
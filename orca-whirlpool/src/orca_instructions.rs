@@ -1,5 +1,17 @@
 use crate::constants;
 use crate::instructions::{
+    close_bundled_position::{
+        CloseBundledPositionInstruction, CloseBundledPositionInstructionAccounts,
+    },
+    close_position::{ClosePositionInstruction, ClosePositionInstructionAccounts},
+    close_position_with_token_extensions::{
+        ClosePositionWithTokenExtensionsInstruction,
+        ClosePositionWithTokenExtensionsInstructionAccounts,
+    },
+    collect_fees::{CollectFeesInstruction, CollectFeesInstructionAccounts},
+    collect_fees_v2::{CollectFeesInstructionAccountsV2, CollectFeesInstructionV2},
+    collect_reward::{CollectRewardInstruction, CollectRewardInstructionAccounts},
+    collect_reward_v2::{CollectRewardInstructionAccountsV2, CollectRewardInstructionV2},
     decrease_liquidity::{DecreaseLiquidityInstruction, DecreaseLiquidityInstructionAccounts},
     decrease_liquidity_v2::{
         DecreaseLiquidityInstructionAccountsV2, DecreaseLiquidityInstructionV2,
@@ -8,8 +20,40 @@ use crate::instructions::{
     increase_liquidity_v2::{
         IncreaseLiquidityInstructionAccountsV2, IncreaseLiquidityInstructionV2,
     },
+    initialize_config::{InitializeConfigInstruction, InitializeConfigInstructionAccounts},
+    initialize_fee_tier::{InitializeFeeTierInstruction, InitializeFeeTierInstructionAccounts},
     initialize_pool::{InitializePoolInstruction, InitializePoolInstructionAccounts},
     initialize_pool_v2::{InitializePoolInstructionAccountsV2, InitializePoolInstructionV2},
+    initialize_position_bundle::{
+        InitializePositionBundleInstruction, InitializePositionBundleInstructionAccounts,
+    },
+    initialize_position_bundle_with_metadata::{
+        InitializePositionBundleWithMetadataInstruction,
+        InitializePositionBundleWithMetadataInstructionAccounts,
+    },
+    initialize_reward::{InitializeRewardInstruction, InitializeRewardInstructionAccounts},
+    initialize_reward_v2::{InitializeRewardInstructionAccountsV2, InitializeRewardInstructionV2},
+    open_bundled_position::{
+        OpenBundledPositionInstruction, OpenBundledPositionInstructionAccounts,
+    },
+    open_position::{OpenPositionInstruction, OpenPositionInstructionAccounts},
+    open_position_with_metadata::{
+        OpenPositionWithMetadataInstruction, OpenPositionWithMetadataInstructionAccounts,
+    },
+    open_position_with_token_extensions::{
+        OpenPositionWithTokenExtensionsInstruction,
+        OpenPositionWithTokenExtensionsInstructionAccounts,
+    },
+    set_default_fee_rate::{SetDefaultFeeRateInstruction, SetDefaultFeeRateInstructionAccounts},
+    set_default_protocol_fee_rate::{
+        SetDefaultProtocolFeeRateInstruction, SetDefaultProtocolFeeRateInstructionAccounts,
+    },
+    set_fee_rate::{SetFeeRateInstruction, SetFeeRateInstructionAccounts},
+    set_protocol_fee_rate::{SetProtocolFeeRateInstruction, SetProtocolFeeRateInstructionAccounts},
+    set_reward_emissions::{SetRewardEmissionsInstruction, SetRewardEmissionsInstructionAccounts},
+    set_reward_emissions_v2::{
+        SetRewardEmissionsInstructionAccountsV2, SetRewardEmissionsInstructionV2,
+    },
     swap::{SwapInstruction, SwapInstructionAccounts},
     swap_v2::{SwapInstructionAccountsV2, SwapInstructionV2},
     two_hop_swap::{TwoHopSwapInstruction, TwoHopSwapInstructionAccounts},
@@ -51,6 +95,89 @@ pub enum OrcaInstructions<'a> {
     TwoHopSwapV2(TwoHopSwapInstructionV2, TwoHopSwapInstructionAccountsV2<'a>),
     Swap(SwapInstruction, SwapInstructionAccounts<'a>),
     SwapV2(SwapInstructionV2, SwapInstructionAccountsV2<'a>),
+    OpenPosition(OpenPositionInstruction, OpenPositionInstructionAccounts<'a>),
+    OpenPositionWithMetadata(
+        OpenPositionWithMetadataInstruction,
+        OpenPositionWithMetadataInstructionAccounts<'a>,
+    ),
+    OpenPositionWithTokenExtensions(
+        OpenPositionWithTokenExtensionsInstruction,
+        OpenPositionWithTokenExtensionsInstructionAccounts<'a>,
+    ),
+    ClosePosition(
+        ClosePositionInstruction,
+        ClosePositionInstructionAccounts<'a>,
+    ),
+    ClosePositionWithTokenExtensions(
+        ClosePositionWithTokenExtensionsInstruction,
+        ClosePositionWithTokenExtensionsInstructionAccounts<'a>,
+    ),
+    InitializePositionBundle(
+        InitializePositionBundleInstruction,
+        InitializePositionBundleInstructionAccounts<'a>,
+    ),
+    InitializePositionBundleWithMetadata(
+        InitializePositionBundleWithMetadataInstruction,
+        InitializePositionBundleWithMetadataInstructionAccounts<'a>,
+    ),
+    OpenBundledPosition(
+        OpenBundledPositionInstruction,
+        OpenBundledPositionInstructionAccounts<'a>,
+    ),
+    CloseBundledPosition(
+        CloseBundledPositionInstruction,
+        CloseBundledPositionInstructionAccounts<'a>,
+    ),
+    CollectFees(CollectFeesInstruction, CollectFeesInstructionAccounts<'a>),
+    CollectFeesV2(
+        CollectFeesInstructionV2,
+        CollectFeesInstructionAccountsV2<'a>,
+    ),
+    InitializeReward(
+        InitializeRewardInstruction,
+        InitializeRewardInstructionAccounts<'a>,
+    ),
+    InitializeRewardV2(
+        InitializeRewardInstructionV2,
+        InitializeRewardInstructionAccountsV2<'a>,
+    ),
+    SetRewardEmissions(
+        SetRewardEmissionsInstruction,
+        SetRewardEmissionsInstructionAccounts<'a>,
+    ),
+    SetRewardEmissionsV2(
+        SetRewardEmissionsInstructionV2,
+        SetRewardEmissionsInstructionAccountsV2<'a>,
+    ),
+    CollectReward(
+        CollectRewardInstruction,
+        CollectRewardInstructionAccounts<'a>,
+    ),
+    CollectRewardV2(
+        CollectRewardInstructionV2,
+        CollectRewardInstructionAccountsV2<'a>,
+    ),
+    InitializeConfig(
+        InitializeConfigInstruction,
+        InitializeConfigInstructionAccounts<'a>,
+    ),
+    InitializeFeeTier(
+        InitializeFeeTierInstruction,
+        InitializeFeeTierInstructionAccounts<'a>,
+    ),
+    SetDefaultFeeRate(
+        SetDefaultFeeRateInstruction,
+        SetDefaultFeeRateInstructionAccounts<'a>,
+    ),
+    SetDefaultProtocolFeeRate(
+        SetDefaultProtocolFeeRateInstruction,
+        SetDefaultProtocolFeeRateInstructionAccounts<'a>,
+    ),
+    SetFeeRate(SetFeeRateInstruction, SetFeeRateInstructionAccounts<'a>),
+    SetProtocolFeeRate(
+        SetProtocolFeeRateInstruction,
+        SetProtocolFeeRateInstructionAccounts<'a>,
+    ),
 }
 
 impl<'a> OrcaInstructions<'a> {
@@ -137,6 +264,187 @@ impl<'a> OrcaInstructions<'a> {
                 >(&mut rest, instruction_view)?;
                 Some(OrcaInstructions::SwapV2(data, input_accounts))
             }
+            x if x == constants::DiscriminatorConstants::OPEN_POSITION => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    OpenPositionInstruction,
+                    OpenPositionInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::OpenPosition(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::OPEN_POSITION_WITH_METADATA => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    OpenPositionWithMetadataInstruction,
+                    OpenPositionWithMetadataInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::OpenPositionWithMetadata(
+                    data,
+                    input_accounts,
+                ))
+            }
+            x if x == constants::DiscriminatorConstants::OPEN_POSITION_WITH_TOKEN_EXTENSIONS => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    OpenPositionWithTokenExtensionsInstruction,
+                    OpenPositionWithTokenExtensionsInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::OpenPositionWithTokenExtensions(
+                    data,
+                    input_accounts,
+                ))
+            }
+            x if x == constants::DiscriminatorConstants::CLOSE_POSITION => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    ClosePositionInstruction,
+                    ClosePositionInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::ClosePosition(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::CLOSE_POSITION_WITH_TOKEN_EXTENSIONS => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    ClosePositionWithTokenExtensionsInstruction,
+                    ClosePositionWithTokenExtensionsInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::ClosePositionWithTokenExtensions(
+                    data,
+                    input_accounts,
+                ))
+            }
+            x if x == constants::DiscriminatorConstants::INITIALIZE_POSITION_BUNDLE => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    InitializePositionBundleInstruction,
+                    InitializePositionBundleInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::InitializePositionBundle(
+                    data,
+                    input_accounts,
+                ))
+            }
+            x if x
+                == constants::DiscriminatorConstants::INITIALIZE_POSITION_BUNDLE_WITH_METADATA =>
+            {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    InitializePositionBundleWithMetadataInstruction,
+                    InitializePositionBundleWithMetadataInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::InitializePositionBundleWithMetadata(
+                    data,
+                    input_accounts,
+                ))
+            }
+            x if x == constants::DiscriminatorConstants::OPEN_BUNDLED_POSITION => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    OpenBundledPositionInstruction,
+                    OpenBundledPositionInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::OpenBundledPosition(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::CLOSE_BUNDLED_POSITION => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    CloseBundledPositionInstruction,
+                    CloseBundledPositionInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::CloseBundledPosition(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::COLLECT_FEES => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    CollectFeesInstruction,
+                    CollectFeesInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::CollectFees(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::COLLECT_FEES_V2 => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    CollectFeesInstructionV2,
+                    CollectFeesInstructionAccountsV2,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::CollectFeesV2(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::INITIALIZE_REWARD => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    InitializeRewardInstruction,
+                    InitializeRewardInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::InitializeReward(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::INITIALIZE_REWARD_V2 => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    InitializeRewardInstructionV2,
+                    InitializeRewardInstructionAccountsV2,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::InitializeRewardV2(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::SET_REWARD_EMISSIONS => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    SetRewardEmissionsInstruction,
+                    SetRewardEmissionsInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::SetRewardEmissions(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::SET_REWARD_EMISSIONS_V2 => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    SetRewardEmissionsInstructionV2,
+                    SetRewardEmissionsInstructionAccountsV2,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::SetRewardEmissionsV2(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::COLLECT_REWARD => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    CollectRewardInstruction,
+                    CollectRewardInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::CollectReward(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::COLLECT_REWARD_V2 => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    CollectRewardInstructionV2,
+                    CollectRewardInstructionAccountsV2,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::CollectRewardV2(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::INITIALIZE_CONFIG => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    InitializeConfigInstruction,
+                    InitializeConfigInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::InitializeConfig(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::INITIALIZE_FEE_TIER => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    InitializeFeeTierInstruction,
+                    InitializeFeeTierInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::InitializeFeeTier(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::SET_DEFAULT_FEE_RATE => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    SetDefaultFeeRateInstruction,
+                    SetDefaultFeeRateInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::SetDefaultFeeRate(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::SET_DEFAULT_PROTOCOL_FEE_RATE => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    SetDefaultProtocolFeeRateInstruction,
+                    SetDefaultProtocolFeeRateInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::SetDefaultProtocolFeeRate(
+                    data,
+                    input_accounts,
+                ))
+            }
+            x if x == constants::DiscriminatorConstants::SET_FEE_RATE => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    SetFeeRateInstruction,
+                    SetFeeRateInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::SetFeeRate(data, input_accounts))
+            }
+            x if x == constants::DiscriminatorConstants::SET_PROTOCOL_FEE_RATE => {
+                let (data, input_accounts) = Self::deserialize_instruction::<
+                    SetProtocolFeeRateInstruction,
+                    SetProtocolFeeRateInstructionAccounts,
+                >(&mut rest, instruction_view)?;
+                Some(OrcaInstructions::SetProtocolFeeRate(data, input_accounts))
+            }
             _ => None,
         }
     }
@@ -1,6 +1,12 @@
+use std::collections::HashMap;
+
+use substreams::scalar::BigInt;
 use substreams_solana::pb::sf::solana::r#type::v1::ConfirmedTransaction;
 use substreams_solana::Address;
 
+use crate::constants::ZERO_STRING;
+use crate::utils::TokenTransfer;
+
 pub trait BalanceOf {
     fn balance_of(
         &self,
@@ -9,6 +15,7 @@ pub trait BalanceOf {
     ) -> (Option<String>, Option<String>);
     fn pre_balance_of(&self, pool_address: &Address, mint_address: &Address) -> Option<String>;
     fn post_balance_of(&self, pool_address: &Address, mint_address: &Address) -> Option<String>;
+    fn decimals_of(&self, token_mint: &Address) -> Option<u32>;
 }
 
 impl BalanceOf for &ConfirmedTransaction {
@@ -62,4 +69,92 @@ impl BalanceOf for &ConfirmedTransaction {
 
         (pre_balance, post_balance)
     }
+
+    fn decimals_of(&self, token_mint: &Address) -> Option<u32> {
+        let meta = self.meta.as_ref()?;
+
+        meta.post_token_balances
+            .iter()
+            .chain(meta.pre_token_balances.iter())
+            .find(|balance| balance.mint == token_mint.to_string())
+            .and_then(|balance| balance.ui_token_amount.as_ref())
+            .map(|ui_token_amount| ui_token_amount.decimals)
+    }
+}
+
+/// Balances of the token accounts touched by a single Whirlpool instruction, rebuilt from
+/// the token transfers it made rather than from the balances of the whole transaction.
+pub struct InstructionBalances<'a> {
+    pub confirmed_txn: &'a ConfirmedTransaction,
+    // Token transfers made by the instruction itself.
+    pub transfers: Vec<TokenTransfer>,
+    // Net change of each token account made by the transaction before the instruction.
+    pub prior_changes: HashMap<String, BigInt>,
+}
+
+impl InstructionBalances<'_> {
+    fn change_of(&self, account: &str) -> Option<BigInt> {
+        self.transfers
+            .iter()
+            .filter(|transfer| transfer.source == account || transfer.destination == account)
+            .map(|transfer| transfer.change_of(account))
+            .reduce(|total, change| total + change)
+    }
+
+    /// Token-2022 transfer fees withheld on the transfers the instruction made from or to
+    /// `account`, if it made any.
+    pub fn transfer_fee_of(&self, account: &Address) -> Option<String> {
+        let account = account.to_string();
+
+        self.transfers
+            .iter()
+            .filter(|transfer| transfer.source == account || transfer.destination == account)
+            .map(|transfer| BigInt::from(transfer.fee))
+            .reduce(|total, fee| total + fee)
+            .map(|fee| fee.to_string())
+    }
+}
+
+impl BalanceOf for InstructionBalances<'_> {
+    fn pre_balance_of(&self, pool_address: &Address, mint_address: &Address) -> Option<String> {
+        let txn_pre_balance = self
+            .confirmed_txn
+            .pre_balance_of(pool_address, mint_address)
+            .unwrap_or(ZERO_STRING.to_string());
+        let prior_change = self
+            .prior_changes
+            .get(&mint_address.to_string())
+            .cloned()
+            .unwrap_or_default();
+
+        Some((BigInt::try_from(txn_pre_balance).unwrap_or_default() + prior_change).to_string())
+    }
+
+    fn post_balance_of(&self, pool_address: &Address, mint_address: &Address) -> Option<String> {
+        let change = self.change_of(&mint_address.to_string())?;
+        let pre_balance = self.pre_balance_of(pool_address, mint_address)?;
+
+        Some((BigInt::try_from(pre_balance).unwrap_or_default() + change).to_string())
+    }
+
+    fn balance_of(
+        &self,
+        pool_address: &Address,
+        mint_address: &Address,
+    ) -> (Option<String>, Option<String>) {
+        // Fall back to the balances of the whole transaction when the instruction
+        // made no transfer from or to the account.
+        if self.change_of(&mint_address.to_string()).is_none() {
+            return self.confirmed_txn.balance_of(pool_address, mint_address);
+        }
+
+        let pre_balance = self.pre_balance_of(pool_address, mint_address);
+        let post_balance = self.post_balance_of(pool_address, mint_address);
+
+        (pre_balance, post_balance)
+    }
+
+    fn decimals_of(&self, token_mint: &Address) -> Option<u32> {
+        self.confirmed_txn.decimals_of(token_mint)
+    }
 }
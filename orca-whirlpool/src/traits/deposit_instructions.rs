@@ -6,10 +6,14 @@ use crate::{
 pub trait DepositInstruction {
     fn whirlpool(&self) -> String;
     fn position_authority(&self) -> String;
+    fn position(&self) -> String;
+    fn position_token_account(&self) -> String;
     fn amount_a(&self) -> String;
     fn amount_b(&self) -> String;
     fn amount_a_post(&self) -> String;
     fn amount_b_post(&self) -> String;
+    fn transfer_fee_a(&self) -> String;
+    fn transfer_fee_b(&self) -> String;
     fn liquidity_amount(&self) -> String;
 }
 
@@ -28,6 +32,20 @@ impl DepositInstruction for IncreaseLiquidity {
             .unwrap_or_default()
     }
 
+    fn position(&self) -> String {
+        self.accounts
+            .as_ref()
+            .map(|a| a.position.clone())
+            .unwrap_or_default()
+    }
+
+    fn position_token_account(&self) -> String {
+        self.accounts
+            .as_ref()
+            .map(|a| a.position_token_account.clone())
+            .unwrap_or_default()
+    }
+
     fn amount_a(&self) -> String {
         self.instruction
             .as_ref()
@@ -56,6 +74,15 @@ impl DepositInstruction for IncreaseLiquidity {
             .unwrap_or_else(|| ZERO_STRING.to_string())
     }
 
+    // Legacy instructions only support the Token program, which charges no transfer fees.
+    fn transfer_fee_a(&self) -> String {
+        ZERO_STRING.to_string()
+    }
+
+    fn transfer_fee_b(&self) -> String {
+        ZERO_STRING.to_string()
+    }
+
     fn liquidity_amount(&self) -> String {
         self.instruction
             .as_ref()
@@ -79,6 +106,20 @@ impl DepositInstruction for IncreaseLiquidityV2 {
             .unwrap_or_default()
     }
 
+    fn position(&self) -> String {
+        self.accounts
+            .as_ref()
+            .map(|a| a.position.clone())
+            .unwrap_or_default()
+    }
+
+    fn position_token_account(&self) -> String {
+        self.accounts
+            .as_ref()
+            .map(|a| a.position_token_account.clone())
+            .unwrap_or_default()
+    }
+
     fn amount_a(&self) -> String {
         self.instruction
             .as_ref()
@@ -107,6 +148,20 @@ impl DepositInstruction for IncreaseLiquidityV2 {
             .unwrap_or_else(|| ZERO_STRING.to_string())
     }
 
+    fn transfer_fee_a(&self) -> String {
+        self.instruction
+            .as_ref()
+            .and_then(|i| i.transfer_fee_a.clone())
+            .unwrap_or_else(|| ZERO_STRING.to_string())
+    }
+
+    fn transfer_fee_b(&self) -> String {
+        self.instruction
+            .as_ref()
+            .and_then(|i| i.transfer_fee_b.clone())
+            .unwrap_or_else(|| ZERO_STRING.to_string())
+    }
+
     fn liquidity_amount(&self) -> String {
         self.instruction
             .as_ref()
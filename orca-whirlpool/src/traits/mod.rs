@@ -1,5 +1,6 @@
 pub mod account_deserialize;
 pub mod balance_of;
 pub mod deposit_instructions;
+pub mod fee_collection_instructions;
 pub mod swap_instructions;
 pub mod withdraw_instructions;
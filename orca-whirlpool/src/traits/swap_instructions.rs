@@ -9,6 +9,8 @@ pub trait SwapInstruction {
     fn amount_b(&self) -> String;
     fn amount_a_post(&self) -> String;
     fn amount_b_post(&self) -> String;
+    fn transfer_fee_a(&self) -> String;
+    fn transfer_fee_b(&self) -> String;
     fn token_authority(&self) -> String;
     fn whirlpool(&self) -> String;
     fn is_two_hop(&self) -> bool;
@@ -17,7 +19,7 @@ pub trait SwapInstruction {
 
 impl SwapInstruction for TwoHopSwap {
     fn a_to_b(&self) -> bool {
-        self.instruction.as_ref().map_or(false, |i| i.a_to_b_one)
+        self.instruction.as_ref().is_some_and(|i| i.a_to_b_one)
     }
 
     fn amount_a(&self) -> String {
@@ -48,6 +50,15 @@ impl SwapInstruction for TwoHopSwap {
             .unwrap_or_else(|| ZERO_STRING.to_string())
     }
 
+    // Legacy instructions only support the Token program, which charges no transfer fees.
+    fn transfer_fee_a(&self) -> String {
+        ZERO_STRING.to_string()
+    }
+
+    fn transfer_fee_b(&self) -> String {
+        ZERO_STRING.to_string()
+    }
+
     fn token_authority(&self) -> String {
         self.accounts
             .as_ref()
@@ -75,7 +86,7 @@ struct TwoHopSwapSecondHop(TwoHopSwap);
 
 impl SwapInstruction for TwoHopSwapSecondHop {
     fn a_to_b(&self) -> bool {
-        self.0.instruction.as_ref().map_or(false, |i| i.a_to_b_two)
+        self.0.instruction.as_ref().is_some_and(|i| i.a_to_b_two)
     }
 
     fn amount_a(&self) -> String {
@@ -110,6 +121,14 @@ impl SwapInstruction for TwoHopSwapSecondHop {
             .unwrap_or_else(|| ZERO_STRING.to_string())
     }
 
+    fn transfer_fee_a(&self) -> String {
+        ZERO_STRING.to_string()
+    }
+
+    fn transfer_fee_b(&self) -> String {
+        ZERO_STRING.to_string()
+    }
+
     fn token_authority(&self) -> String {
         self.0
             .accounts
@@ -137,7 +156,7 @@ impl SwapInstruction for TwoHopSwapSecondHop {
 
 impl SwapInstruction for TwoHopSwapV2 {
     fn a_to_b(&self) -> bool {
-        self.instruction.as_ref().map_or(false, |i| i.a_to_b_one)
+        self.instruction.as_ref().is_some_and(|i| i.a_to_b_one)
     }
 
     fn amount_a(&self) -> String {
@@ -168,6 +187,20 @@ impl SwapInstruction for TwoHopSwapV2 {
             .unwrap_or_else(|| ZERO_STRING.to_string())
     }
 
+    fn transfer_fee_a(&self) -> String {
+        self.instruction
+            .as_ref()
+            .and_then(|i| i.transfer_fee_a_one.clone())
+            .unwrap_or_else(|| ZERO_STRING.to_string())
+    }
+
+    fn transfer_fee_b(&self) -> String {
+        self.instruction
+            .as_ref()
+            .and_then(|i| i.transfer_fee_b_one.clone())
+            .unwrap_or_else(|| ZERO_STRING.to_string())
+    }
+
     fn token_authority(&self) -> String {
         self.accounts
             .as_ref()
@@ -195,7 +228,7 @@ struct TwoHopSwapV2SecondHop(TwoHopSwapV2);
 
 impl SwapInstruction for TwoHopSwapV2SecondHop {
     fn a_to_b(&self) -> bool {
-        self.0.instruction.as_ref().map_or(false, |i| i.a_to_b_two)
+        self.0.instruction.as_ref().is_some_and(|i| i.a_to_b_two)
     }
 
     fn amount_a(&self) -> String {
@@ -230,6 +263,22 @@ impl SwapInstruction for TwoHopSwapV2SecondHop {
             .unwrap_or_else(|| ZERO_STRING.to_string())
     }
 
+    fn transfer_fee_a(&self) -> String {
+        self.0
+            .instruction
+            .as_ref()
+            .and_then(|i| i.transfer_fee_a_two.clone())
+            .unwrap_or_else(|| ZERO_STRING.to_string())
+    }
+
+    fn transfer_fee_b(&self) -> String {
+        self.0
+            .instruction
+            .as_ref()
+            .and_then(|i| i.transfer_fee_b_two.clone())
+            .unwrap_or_else(|| ZERO_STRING.to_string())
+    }
+
     fn token_authority(&self) -> String {
         self.0
             .accounts
@@ -257,7 +306,7 @@ impl SwapInstruction for TwoHopSwapV2SecondHop {
 
 impl SwapInstruction for OrcaSwap {
     fn a_to_b(&self) -> bool {
-        self.instruction.as_ref().map_or(false, |i| i.a_to_b)
+        self.instruction.as_ref().is_some_and(|i| i.a_to_b)
     }
 
     fn amount_a(&self) -> String {
@@ -288,6 +337,14 @@ impl SwapInstruction for OrcaSwap {
             .unwrap_or_else(|| ZERO_STRING.to_string())
     }
 
+    fn transfer_fee_a(&self) -> String {
+        ZERO_STRING.to_string()
+    }
+
+    fn transfer_fee_b(&self) -> String {
+        ZERO_STRING.to_string()
+    }
+
     fn token_authority(&self) -> String {
         self.accounts
             .as_ref()
@@ -310,7 +367,7 @@ impl SwapInstruction for OrcaSwap {
 
 impl SwapInstruction for OrcaSwapV2 {
     fn a_to_b(&self) -> bool {
-        self.instruction.as_ref().map_or(false, |i| i.a_to_b)
+        self.instruction.as_ref().is_some_and(|i| i.a_to_b)
     }
 
     fn amount_a(&self) -> String {
@@ -341,6 +398,20 @@ impl SwapInstruction for OrcaSwapV2 {
             .unwrap_or_else(|| ZERO_STRING.to_string())
     }
 
+    fn transfer_fee_a(&self) -> String {
+        self.instruction
+            .as_ref()
+            .and_then(|i| i.transfer_fee_a.clone())
+            .unwrap_or_else(|| ZERO_STRING.to_string())
+    }
+
+    fn transfer_fee_b(&self) -> String {
+        self.instruction
+            .as_ref()
+            .and_then(|i| i.transfer_fee_b.clone())
+            .unwrap_or_else(|| ZERO_STRING.to_string())
+    }
+
     fn token_authority(&self) -> String {
         self.accounts
             .as_ref()
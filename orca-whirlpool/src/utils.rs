@@ -1,6 +1,19 @@
-use substreams::scalar::BigInt;
+use substreams::pb::substreams::store_delta::Operation;
+use substreams::scalar::{BigDecimal, BigInt};
+use substreams::store::{
+    DeltaExt, DeltaInt64, Deltas, StoreDelete, StoreGet, StoreGetBigDecimal, StoreGetBigInt,
+    StoreGetString,
+};
+use substreams_solana::block_view::InstructionView;
 
-use crate::constants::ZERO_STRING;
+use crate::constants::{
+    DEFAULT_FEE_RATES, FEE_RATE_DENOMINATOR, PROTOCOL_FEE_RATE_DENOMINATOR, STABLECOINS,
+    TOKEN_2022_PROGRAM, TOKEN_PROGRAM, TRANSFER_CHECKED_INSTRUCTION,
+    TRANSFER_CHECKED_WITH_FEE_INSTRUCTION, TRANSFER_FEE_EXTENSION_INSTRUCTION,
+    TRANSFER_INSTRUCTION, ZERO_STRING,
+};
+use crate::key_store::{Granularity, StoreKey};
+use crate::pb::messari::orca_whirlpool::v1::{Event, Pool, PoolPrice, Swap};
 
 pub(crate) fn balance_difference(
     pre_balance: Option<String>,
@@ -16,3 +29,310 @@ pub(crate) fn balance_difference(
 
     Some(balance_difference.to_string())
 }
+
+pub(crate) fn default_fee_rate(tick_spacing: u32) -> i64 {
+    DEFAULT_FEE_RATES
+        .iter()
+        .find(|(spacing, _)| *spacing == tick_spacing)
+        .map(|(_, fee_rate)| *fee_rate)
+        .unwrap_or_default()
+}
+
+/// Splits the fee paid on `amount_in` into its total, LP and protocol parts, rounding
+/// the total fee up the same way the Whirlpool program does.
+pub(crate) fn swap_fees(
+    amount_in: &BigInt,
+    fee_rate: i64,
+    protocol_fee_rate: i64,
+) -> (BigInt, BigInt, BigInt) {
+    let fee_rate_denominator = BigInt::from(FEE_RATE_DENOMINATOR);
+
+    let fee_amount = (amount_in.clone() * BigInt::from(fee_rate) + fee_rate_denominator.clone()
+        - BigInt::one())
+        / fee_rate_denominator;
+    let protocol_fee_amount = fee_amount.clone() * BigInt::from(protocol_fee_rate)
+        / BigInt::from(PROTOCOL_FEE_RATE_DENOMINATOR);
+    let lp_fee_amount = fee_amount.clone() - protocol_fee_amount.clone();
+
+    (fee_amount, lp_fee_amount, protocol_fee_amount)
+}
+
+/// Converts a Q64.64 sqrt price into the price of token A in units of token B,
+/// without adjusting for token decimals.
+pub(crate) fn sqrt_price_x64_to_price(sqrt_price_x64: &str) -> BigDecimal {
+    let sqrt_price =
+        BigDecimal::from(BigInt::try_from(sqrt_price_x64.to_string()).unwrap_or_default())
+            / BigDecimal::from(BigInt::from(2).pow(64));
+
+    sqrt_price.clone() * sqrt_price
+}
+
+/// Converts a Q64.64 sqrt price into the tick whose range contains it, i.e. the floor of
+/// log base 1.0001 of the price. Prices sitting exactly on a tick boundary may land one
+/// tick below because of floating point precision.
+pub(crate) fn sqrt_price_x64_to_tick(sqrt_price_x64: &str) -> Option<i32> {
+    let sqrt_price = sqrt_price_x64.parse::<u128>().ok()? as f64 / 2f64.powi(64);
+    if sqrt_price <= 0.0 {
+        return None;
+    }
+
+    Some((2.0 * sqrt_price.ln() / 1.0001f64.ln()).floor() as i32)
+}
+
+/// Approximates the Q64.64 sqrt price of a pool from the ratio of its vault balances,
+/// for swaps whose post-trade sqrt price was not logged.
+pub(crate) fn balances_to_sqrt_price_x64(balance_a: &str, balance_b: &str) -> Option<String> {
+    let balance_a = balance_a.parse::<f64>().ok()?;
+    let balance_b = balance_b.parse::<f64>().ok()?;
+    if balance_a <= 0.0 || balance_b <= 0.0 {
+        return None;
+    }
+
+    Some((((balance_b / balance_a).sqrt() * 2f64.powi(64)) as u128).to_string())
+}
+
+/// Prices of both tokens of a pool at `sqrt_price_x64`, adjusted for token decimals.
+pub(crate) fn pool_price(
+    sqrt_price_x64: &str,
+    decimals_a: u32,
+    decimals_b: u32,
+    approximated: bool,
+) -> PoolPrice {
+    let token_a_price = sqrt_price_x64_to_price(sqrt_price_x64)
+        * BigDecimal::from(BigInt::from(10).pow(decimals_a))
+        / BigDecimal::from(BigInt::from(10).pow(decimals_b));
+    let token_b_price = if token_a_price.is_zero() {
+        BigDecimal::zero()
+    } else {
+        BigDecimal::one() / token_a_price.clone()
+    };
+
+    PoolPrice {
+        sqrt_price_x64: sqrt_price_x64.to_string(),
+        tick: sqrt_price_x64_to_tick(sqrt_price_x64),
+        token_a_price: token_a_price.to_string(),
+        token_b_price: token_b_price.to_string(),
+        approximated,
+    }
+}
+
+/// Converts a native `amount` of a token with `decimals` into whole tokens.
+pub(crate) fn normalize_amount(amount: &BigInt, decimals: u32) -> BigDecimal {
+    BigDecimal::from(amount.clone()) / BigDecimal::from(BigInt::from(10).pow(decimals))
+}
+
+/// Decimals of `token` if it is one of the tokens of `pool`.
+pub(crate) fn token_decimals(pool: &Pool, token: &str) -> Option<u32> {
+    if token == pool.token_mint_a {
+        pool.decimals_a
+    } else if token == pool.token_mint_b {
+        pool.decimals_b
+    } else {
+        None
+    }
+}
+
+pub(crate) fn is_stablecoin(token: &str) -> bool {
+    STABLECOINS.contains(&token)
+}
+
+/// USD price of `token`: one dollar for stablecoins, otherwise the last price derived
+/// from its pools by `store_token_prices`.
+pub(crate) fn token_price_usd(
+    token: &str,
+    token_prices_store: &StoreGetBigDecimal,
+) -> Option<BigDecimal> {
+    if is_stablecoin(token) {
+        return Some(BigDecimal::one());
+    }
+
+    token_prices_store.get_last(StoreKey::TokenPrice.get_unique_key(token))
+}
+
+/// USD value of a native `amount` of one of the tokens of `pool`, or zero when the token
+/// has no price yet.
+pub(crate) fn amount_usd(
+    pool: &Pool,
+    token: &str,
+    amount: &str,
+    token_prices_store: &StoreGetBigDecimal,
+) -> BigDecimal {
+    match (
+        token_price_usd(token, token_prices_store),
+        token_decimals(pool, token),
+    ) {
+        (Some(price), Some(decimals)) => {
+            normalize_amount(
+                &BigInt::try_from(amount.to_string())
+                    .unwrap_or_default()
+                    .absolute(),
+                decimals,
+            ) * price
+        }
+        _ => BigDecimal::zero(),
+    }
+}
+
+/// USD value of the balance of `token` held by `pool`, given the token's USD price.
+pub(crate) fn pool_balance_usd(
+    pool: &Pool,
+    token: &str,
+    price_usd: &BigDecimal,
+    pool_balances_store: &StoreGetBigInt,
+) -> BigDecimal {
+    let balance = pool_balances_store
+        .get_last(StoreKey::PoolBalance.get_unique_keys(&pool.address, token))
+        .unwrap_or_default();
+
+    match token_decimals(pool, token) {
+        Some(decimals) => normalize_amount(&balance, decimals) * price_usd.clone(),
+        None => BigDecimal::zero(),
+    }
+}
+
+/// USD volume of `swap`, valued on its input token unless only the output token is priced.
+pub(crate) fn swap_volume_usd(
+    pool: &Pool,
+    swap: &Swap,
+    token_prices_store: &StoreGetBigDecimal,
+) -> BigDecimal {
+    let amount_in_usd = amount_usd(pool, &swap.token_in, &swap.amount_in, token_prices_store);
+    if !amount_in_usd.is_zero() {
+        return amount_in_usd;
+    }
+
+    amount_usd(pool, &swap.token_out, &swap.amount_out, token_prices_store)
+}
+
+/// Amount sent and amount received by the transfer that changed a vault balance by the
+/// signed `vault_change`, with the same sign. The Token-2022 `transfer_fee` is withheld
+/// from the recipient: the vault when it receives tokens, the user when it sends them.
+pub(crate) fn transfer_amounts(vault_change: &str, transfer_fee: &str) -> (String, String) {
+    let vault_change = BigInt::try_from(vault_change.to_string()).unwrap_or_default();
+    let transfer_fee = BigInt::try_from(transfer_fee.to_string()).unwrap_or_default();
+
+    if vault_change < BigInt::zero() {
+        (
+            vault_change.to_string(),
+            (vault_change + transfer_fee).to_string(),
+        )
+    } else {
+        (
+            (vault_change.clone() + transfer_fee).to_string(),
+            vault_change.to_string(),
+        )
+    }
+}
+
+/// Deletes the keys of the `snapshot` of every period that was rolled over, as recorded
+/// in the `store_rolled_periods` deltas. Windowed stores only ever write to the current
+/// period, so the previously rolled period is the only one that can still hold keys, no
+/// matter how many periods passed without a block being processed.
+pub(crate) fn delete_rolled_snapshots<F>(
+    store: &impl StoreDelete,
+    rolled_periods_deltas: &Deltas<DeltaInt64>,
+    snapshot: F,
+) where
+    F: Fn(Granularity, i64) -> Option<StoreKey>,
+{
+    rolled_periods_deltas
+        .iter()
+        .operation_eq(Operation::Update)
+        .filter(|delta| delta.old_value != delta.new_value)
+        .for_each(|delta| {
+            let rolled_snapshot = Granularity::ALL
+                .into_iter()
+                .find(|granularity| granularity.last_rolled_period().unique_id() == delta.key)
+                .and_then(|granularity| snapshot(granularity, delta.old_value));
+
+            if let Some(rolled_snapshot) = rolled_snapshot {
+                store.delete_prefix(0, &rolled_snapshot.unique_id());
+            }
+        });
+}
+
+/// Current holder of the NFT of `position`, falling back to the authority that signed the
+/// instruction for positions whose owner is unknown, such as bundled positions.
+pub(crate) fn position_owner(
+    position: &str,
+    position_authority: String,
+    position_owners_store: &StoreGetString,
+) -> String {
+    position_owners_store
+        .get_last(StoreKey::PositionOwner.get_unique_key(position))
+        .unwrap_or(position_authority)
+}
+
+/// Builds a unique ID for an entity derived from `event`. `hop` tells apart the
+/// entities derived from the same instruction, such as both legs of a two-hop swap.
+pub(crate) fn instruction_id(prefix: &str, event: &Event, hop: u32) -> String {
+    format!(
+        "{}-{}-{}-{}-{}",
+        prefix, event.txn_id, event.instruction_index, event.inner_instruction_index, hop
+    )
+}
+
+#[derive(Clone, Debug)]
+pub struct TokenTransfer {
+    pub source: String,
+    pub destination: String,
+    pub amount: u64,
+    // Token-2022 transfer fee withheld in the destination account out of `amount`.
+    pub fee: u64,
+    // Whether the transfer was made by the Token-2022 program, the only one charging fees.
+    pub token_2022: bool,
+}
+
+impl TokenTransfer {
+    /// Signed change of `account`'s balance caused by this transfer. The destination is
+    /// only credited what is left once the transfer fee is withheld.
+    pub fn change_of(&self, account: &str) -> BigInt {
+        let mut change = BigInt::zero();
+        if self.destination == account {
+            change = change + BigInt::from(self.amount - self.fee);
+        }
+        if self.source == account {
+            change = change - BigInt::from(self.amount);
+        }
+        change
+    }
+}
+
+/// Decodes an SPL Token or Token-2022 `Transfer` / `TransferChecked` instruction, or a
+/// Token-2022 `TransferCheckedWithFee`. Only the latter carries its transfer fee; the fees
+/// of the others are filled in from the transaction balances, see `map_block`.
+pub(crate) fn parse_token_transfer(instruction: &InstructionView) -> Option<TokenTransfer> {
+    let program_id = instruction.program_id();
+    let token_2022 = program_id == TOKEN_2022_PROGRAM;
+    if program_id != TOKEN_PROGRAM && !token_2022 {
+        return None;
+    }
+
+    let data = instruction.data();
+    let accounts = instruction.accounts();
+    let read_u64 = |offset: usize| -> Option<u64> {
+        Some(u64::from_le_bytes(
+            data.get(offset..offset + 8)?.try_into().ok()?,
+        ))
+    };
+
+    let (destination_index, amount, fee) = match *data.first()? {
+        TRANSFER_INSTRUCTION => (1, read_u64(1)?, 0),
+        TRANSFER_CHECKED_INSTRUCTION => (2, read_u64(1)?, 0),
+        // Tags, amount, decimals and fee.
+        TRANSFER_FEE_EXTENSION_INSTRUCTION
+            if token_2022 && *data.get(1)? == TRANSFER_CHECKED_WITH_FEE_INSTRUCTION =>
+        {
+            (2, read_u64(2)?, read_u64(11)?)
+        }
+        _ => return None,
+    };
+
+    Some(TokenTransfer {
+        source: accounts.first()?.to_string(),
+        destination: accounts.get(destination_index)?.to_string(),
+        amount,
+        fee: fee.min(amount),
+        token_2022,
+    })
+}